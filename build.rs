@@ -1,5 +1,39 @@
 extern crate napi_build;
 
+use std::env;
+use std::fs;
+use std::path::Path;
+
+// Embeds every `questions/YEAR_DAY.md` file, so that new write-ups are picked up without code changes.
+fn embed_questions() {
+    let dir = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("questions");
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let mut files: Vec<_> = fs::read_dir(&dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    files.sort();
+    let mut entries = String::new();
+    for path in files {
+        let name = path.file_stem().unwrap().to_str().unwrap();
+        let Some((year, day)) = name.split_once('_') else {
+            continue;
+        };
+        let (Ok(year), Ok(day)) = (year.parse::<u16>(), day.parse::<u8>()) else {
+            continue;
+        };
+        if path.extension().is_some_and(|extension| extension == "md") {
+            entries += &format!(
+                "    ({year}, {day}, include_str!({:?})),\n",
+                path.display().to_string()
+            );
+        }
+    }
+    let out = Path::new(&env::var("OUT_DIR").unwrap()).join("questions.rs");
+    fs::write(out, format!("&[\n{entries}]\n")).unwrap();
+}
+
 fn main() {
     napi_build::setup();
+    embed_questions();
 }
@@ -0,0 +1,157 @@
+use crate::solver::{parse, Limits, ParseError, Puzzle, SolveError};
+use pest::Parser;
+use std::collections::HashMap;
+
+#[derive(pest_derive::Parser)]
+#[grammar = "answers.pest"]
+struct AnswersParser;
+
+pub struct Answers(HashMap<(u16, u8), [Option<String>; 2]>);
+
+impl Default for Answers {
+    fn default() -> Self {
+        Self::try_from(include_str!("../answers.toml")).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Answers {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut answers = HashMap::new();
+        let file = AnswersParser::parse(Rule::answers, input)?.next().unwrap();
+        for table in file
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::table)
+        {
+            let mut pairs = table.into_inner();
+            let year = parse::pair_value(&pairs.next().unwrap())?;
+            let day = parse::pair_value(&pairs.next().unwrap())?;
+            let entry: &mut [Option<String>; 2] = answers.entry((year, day)).or_default();
+            for pair in pairs {
+                let mut pairs = pair.into_inner();
+                let part = match pairs.next().unwrap().as_str() {
+                    "part1" => 0,
+                    _ => 1,
+                };
+                let value = pairs.next().unwrap().into_inner().next().unwrap();
+                entry[part] = Some(value.as_str().to_string());
+            }
+        }
+        Ok(Self(answers))
+    }
+}
+
+pub enum Check {
+    Correct,
+    Wrong { expected: String, actual: String },
+    Missing(String),
+    Failed(SolveError),
+}
+
+impl Answers {
+    pub fn verify(&self, puzzle: &'static Puzzle, limits: &Limits) -> [Check; 2] {
+        let expected = self.0.get(&(puzzle.year, puzzle.day));
+        let check = |part: usize, actual| match (actual, expected.and_then(|e| e[part].clone())) {
+            (Err(error), _) => Check::Failed(error),
+            (Ok(actual), None) => Check::Missing(actual),
+            (Ok(actual), Some(expected)) if actual == expected => Check::Correct,
+            (Ok(actual), Some(expected)) => Check::Wrong { expected, actual },
+        };
+        [
+            check(
+                0,
+                puzzle.solve_within(
+                    None,
+                    limits,
+                    |solver, progress| {
+                        solver
+                            .solve_first_part_with(progress)
+                            .map(|answer| answer.to_string())
+                    },
+                    &|_| true,
+                ),
+            ),
+            check(
+                1,
+                puzzle.solve_within(
+                    None,
+                    limits,
+                    |solver, progress| {
+                        solver
+                            .solve_second_part_with(progress)
+                            .map(|answer| answer.to_string())
+                    },
+                    &|_| true,
+                ),
+            ),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{find_puzzle, puzzles, with_solver_stack};
+
+    #[test]
+    fn should_parse_answers() {
+        let answers = Answers::try_from(
+            "\
+# comment
+[2015.1]
+part1 = \"232\"
+
+[2022.10] # grid
+part2 = \"\"\"
+#.
+.#
+\"\"\"
+",
+        )
+        .unwrap();
+        assert_eq!(answers.0[&(2015, 1)], [Some("232".to_string()), None]);
+        assert_eq!(answers.0[&(2022, 10)], [None, Some("#.\n.#\n".to_string())]);
+    }
+
+    #[test]
+    fn should_report_invalid_answers() {
+        let error = Answers::try_from("[2015.1]\npart3 = \"1\"").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn should_detect_wrong_answer() {
+        let answers = Answers::try_from("[2015.1]\npart1 = \"0\"").unwrap();
+        let [first, second] = answers.verify(find_puzzle(2015, 1).unwrap(), &Limits::default());
+        assert!(matches!(first, Check::Wrong { expected, .. } if expected == "0"));
+        assert!(matches!(second, Check::Missing(_)));
+    }
+
+    #[test]
+    #[ignore = "solves every bundled input, run it with `cargo test --release -- --ignored`"]
+    fn should_match_every_stored_answer() {
+        with_solver_stack(|| {
+            let answers = Answers::default();
+            // Days whose bundled input is only the puzzle example have no stored answers.
+            for puzzle in puzzles()
+                .iter()
+                .filter(|puzzle| answers.0.contains_key(&(puzzle.year, puzzle.day)))
+            {
+                for (part, check) in answers
+                    .verify(puzzle, &Limits::default())
+                    .into_iter()
+                    .enumerate()
+                {
+                    assert!(
+                        matches!(check, Check::Correct),
+                        "{} Day {} part {} does not match answers.toml",
+                        puzzle.year,
+                        puzzle.day,
+                        part + 1
+                    );
+                }
+            }
+        });
+    }
+}
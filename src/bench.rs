@@ -0,0 +1,232 @@
+use crate::solver::SolveError;
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        let iterations = samples.len();
+        let mean = samples.iter().sum::<Duration>() / iterations as u32;
+        let median = (samples[(iterations - 1) / 2] + samples[iterations / 2]) / 2;
+        let variance = samples
+            .iter()
+            .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+            .sum::<f64>()
+            / iterations as f64;
+        Self {
+            iterations,
+            mean,
+            median,
+            min: samples[0],
+            max: samples[iterations - 1],
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+/// Keeps timing `solve` after a first run that took `first`, until `budget` is spent.
+pub fn repeat(first: Duration, budget: Duration, solve: impl Fn()) -> Stats {
+    let mut samples = vec![first];
+    let mut spent = first;
+    while spent < budget {
+        let iteration = Instant::now();
+        solve();
+        let elapsed = iteration.elapsed();
+        samples.push(elapsed);
+        spent += elapsed;
+    }
+    Stats::new(samples)
+}
+
+/// CPU time spent by the calling thread, which other busy threads don't inflate.
+#[cfg(target_os = "linux")]
+fn thread_time() -> Option<Duration> {
+    use std::ffi::{c_int, c_long};
+
+    #[repr(C)]
+    struct Timespec {
+        tv_sec: c_long,
+        tv_nsec: c_long,
+    }
+
+    extern "C" {
+        fn clock_gettime(clock: c_int, time: *mut Timespec) -> c_int;
+    }
+
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+    let mut time = Timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid timespec for the duration of the call.
+    let status = unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut time) };
+    (status == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn thread_time() -> Option<Duration> {
+    None
+}
+
+/// Runs `solve` once and times it in CPU time of the thread where available, or else on the wall
+/// clock, so that puzzles solved side by side are timed as if they ran alone.
+pub fn time_once<T>(
+    solve: impl FnOnce() -> Result<T, SolveError>,
+) -> Result<(T, Duration), SolveError> {
+    let wall = Instant::now();
+    let start = thread_time();
+    let answer = solve()?;
+    let elapsed = match (start, thread_time()) {
+        (Some(start), Some(end)) => end.saturating_sub(start),
+        _ => wall.elapsed(),
+    };
+    Ok((answer, elapsed))
+}
+
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub result: Result<Stats, SolveError>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Table,
+    Json,
+    Csv,
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos() as u64
+}
+
+pub fn report(records: &[Record], format: Format) -> String {
+    match format {
+        Format::Table => {
+            let mut lines = vec![format!(
+                "{:<12} {:>4} {:>10} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                "Puzzle", "Part", "Iterations", "Mean", "Median", "Min", "Max", "Std dev"
+            )];
+            for record in records {
+                let puzzle = format!("{} Day {}", record.year, record.day);
+                lines.push(match &record.result {
+                    Ok(stats) => format!(
+                        "{:<12} {:>4} {:>10} {:>12} {:>12} {:>12} {:>12} {:>12}",
+                        puzzle,
+                        record.part,
+                        stats.iterations,
+                        format!("{:?}", stats.mean),
+                        format!("{:?}", stats.median),
+                        format!("{:?}", stats.min),
+                        format!("{:?}", stats.max),
+                        format!("{:?}", stats.std_dev),
+                    ),
+                    Err(error) => format!("{:<12} {:>4} {error}", puzzle, record.part),
+                });
+            }
+            lines.join("\n") + "\n"
+        }
+        Format::Json => {
+            let records: Vec<Value> = records
+                .iter()
+                .map(|record| match &record.result {
+                    Ok(stats) => json!({
+                        "year": record.year,
+                        "day": record.day,
+                        "part": record.part,
+                        "iterations": stats.iterations,
+                        "mean_ns": nanos(stats.mean),
+                        "median_ns": nanos(stats.median),
+                        "min_ns": nanos(stats.min),
+                        "max_ns": nanos(stats.max),
+                        "std_dev_ns": nanos(stats.std_dev),
+                    }),
+                    Err(error) => json!({
+                        "year": record.year,
+                        "day": record.day,
+                        "part": record.part,
+                        "error": error.to_string(),
+                    }),
+                })
+                .collect();
+            serde_json::to_string_pretty(&records).unwrap() + "\n"
+        }
+        Format::Csv => {
+            let mut lines = vec![
+                "year,day,part,iterations,mean_ns,median_ns,min_ns,max_ns,std_dev_ns,error"
+                    .to_string(),
+            ];
+            for record in records {
+                let prefix = format!("{},{},{}", record.year, record.day, record.part);
+                lines.push(match &record.result {
+                    Ok(stats) => format!(
+                        "{prefix},{},{},{},{},{},{},",
+                        stats.iterations,
+                        nanos(stats.mean),
+                        nanos(stats.median),
+                        nanos(stats.min),
+                        nanos(stats.max),
+                        nanos(stats.std_dev),
+                    ),
+                    Err(error) => format!(
+                        "{prefix},,,,,,,\"{}\"",
+                        error.to_string().replace('"', "\"\"")
+                    ),
+                });
+            }
+            lines.join("\n") + "\n"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_compute_stats() {
+        let stats = Stats::new([4, 1, 3, 2].map(Duration::from_millis).to_vec());
+        assert_eq!(stats.iterations, 4);
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.max, Duration::from_millis(4));
+        assert_eq!(stats.std_dev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn should_report_csv() {
+        let records = [
+            Record {
+                year: 2015,
+                day: 1,
+                part: 1,
+                result: Ok(Stats::new(vec![Duration::from_nanos(10)])),
+            },
+            Record {
+                year: 2015,
+                day: 1,
+                part: 2,
+                result: Err(SolveError::NoSolution),
+            },
+        ];
+        assert_eq!(
+            report(&records, Format::Csv),
+            "year,day,part,iterations,mean_ns,median_ns,min_ns,max_ns,std_dev_ns,error\n\
+             2015,1,1,1,10,10,10,10,0,\n\
+             2015,1,2,,,,,,,\"the puzzle has no solution for this input\"\n"
+        );
+    }
+}
@@ -1,3 +1,5 @@
 #![feature(linked_list_cursors)]
 pub mod node;
+// The node bindings leave out the parts of the solvers that only the CLI uses.
+#[allow(dead_code, unused_imports)]
 pub(self) mod solver;
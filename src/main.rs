@@ -2,206 +2,625 @@
 extern crate core;
 extern crate pest;
 
-use clap::{Parser, ValueEnum};
+use clap::builder::RangedI64ValueParser;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use serde_json::Value;
 use spinners::{Spinner, Spinners};
-use std::fmt::Display;
+use std::cell::RefCell;
+use std::env;
+use std::fs;
+use std::io::{self, IsTerminal, Read};
+use std::path::PathBuf;
+use std::process;
+use std::slice;
+use std::thread;
 use std::time::{Duration, Instant};
 
+mod answers;
+mod bench;
+mod run;
+mod scaffold;
 mod solver;
+use answers::Check;
 use solver::{
-    Solver, Solver2015_01, Solver2015_02, Solver2015_03, Solver2015_04, Solver2015_05,
-    Solver2015_06, Solver2015_07, Solver2015_08, Solver2015_09, Solver2015_10, Solver2015_12,
-    Solver2015_13, Solver2015_14, Solver2015_15, Solver2015_16, Solver2015_17, Solver2015_18,
-    Solver2015_19, Solver2022_01, Solver2022_02, Solver2022_03, Solver2022_04, Solver2022_05,
-    Solver2022_06, Solver2022_07, Solver2022_08, Solver2022_09, Solver2022_10, Solver2022_11,
-    Solver2022_12, Solver2022_13, Solver2022_14, Solver2022_15, Solver2022_16, Solver2022_17,
-    Solver2022_18, Solver2022_20, Solver2022_21, Solver2022_22, Solver2024_01, Solver2024_02,
-    Solver2024_03, Solver2024_04, Solver2024_05, Solver2024_06, Solver2024_07, Solver2024_08,
-    Solver2024_09, Solver2024_10, Solver2024_11, Solver2024_12, Solver2024_13, Solver2024_14,
-    Solver2024_15, Solver2024_16, Solver2024_17, Solver2024_18, Solver2024_19, Solver2024_20,
-    Solver2024_21, Solver2024_22, Solver2024_23, Solver2024_24, Solver2024_25,
+    check_questions, find_puzzle, find_question, list, puzzles, unsolved, years, Answer, Limits,
+    ProgressHook, ProgressUpdate, Puzzle, SolveError, Solver,
 };
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
-enum Year {
-    #[value(name = "2015")]
-    Year2015,
-    #[value(name = "2022")]
-    Year2022,
-    #[value(name = "2024")]
-    Year2024,
+// The first Advent of Code was in 2015.
+fn year_parser() -> RangedI64ValueParser<u16> {
+    clap::value_parser!(u16).range(2015..)
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
-enum Day {
+fn day_parser() -> RangedI64ValueParser<u8> {
+    clap::value_parser!(u8).range(1..=25)
+}
+
+fn seconds_parser(value: &str) -> Result<Duration, String> {
+    let seconds: f64 = value.parse().map_err(|error| format!("{error}"))?;
+    Duration::try_from_secs_f64(seconds)
+        .map_err(|_| String::from("expected a finite number of seconds that isn't negative"))
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Part {
     #[value(name = "1")]
-    Day1,
+    First,
     #[value(name = "2")]
-    Day2,
-    #[value(name = "3")]
-    Day3,
-    #[value(name = "4")]
-    Day4,
-    #[value(name = "5")]
-    Day5,
-    #[value(name = "6")]
-    Day6,
-    #[value(name = "7")]
-    Day7,
-    #[value(name = "8")]
-    Day8,
-    #[value(name = "9")]
-    Day9,
-    #[value(name = "10")]
-    Day10,
-    #[value(name = "11")]
-    Day11,
-    #[value(name = "12")]
-    Day12,
-    #[value(name = "13")]
-    Day13,
-    #[value(name = "14")]
-    Day14,
-    #[value(name = "15")]
-    Day15,
-    #[value(name = "16")]
-    Day16,
-    #[value(name = "17")]
-    Day17,
-    #[value(name = "18")]
-    Day18,
-    #[value(name = "19")]
-    Day19,
-    #[value(name = "20")]
-    Day20,
-    #[value(name = "21")]
-    Day21,
-    #[value(name = "22")]
-    Day22,
-    #[value(name = "23")]
-    Day23,
-    #[value(name = "24")]
-    Day24,
-    #[value(name = "25")]
-    Day25,
+    Second,
 }
 
 /// Search for a pattern in a file and display the lines that contain it.
 #[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Cli {
-    #[arg(value_enum)]
-    year: Year,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    #[arg(value_parser = year_parser(), required = true)]
+    year: Option<u16>,
+
+    #[arg(value_parser = day_parser(), required = true)]
+    day: Option<u8>,
 
-    #[arg(value_enum)]
-    day: Day,
+    /// Read the puzzle input from a file, or from stdin if the path is `-`
+    #[arg(short, long, value_name = "PATH", conflicts_with = "input_text")]
+    input: Option<PathBuf>,
+
+    /// Use the given text as the puzzle input
+    #[arg(long, value_name = "TEXT")]
+    input_text: Option<String>,
+
+    /// Only solve this part
+    #[arg(short, long, value_enum)]
+    part: Option<Part>,
+
+    /// Solve each part once instead of timing it over a second
+    #[arg(long)]
+    no_bench: bool,
+
+    /// Print the answers as text, or as JSON records with their type, timing and errors
+    #[arg(long, value_enum, default_value_t = run::Format::Text)]
+    format: run::Format,
+
+    #[command(flatten)]
+    limits: LimitArgs,
 }
 
-fn solve<T1: Display, T2: Display>(solver: Box<dyn Solver<T1, T2>>) {
-    let mut sp = Spinner::new(Spinners::Dots, "Solving the first part...".to_string());
-    let start = Instant::now();
-    let first_answer = solver.solve_first_part();
-    let mut count = 1;
-    while start.elapsed() < Duration::from_secs(1) {
-        solver.solve_first_part();
-        count += 1;
+#[derive(Args, Debug)]
+struct LimitArgs {
+    /// Give up on a part that runs longer than this
+    #[arg(long, value_name = "SECONDS", value_parser = seconds_parser)]
+    timeout: Option<Duration>,
+
+    /// Give up on a part once the heap grows by this many megabytes
+    #[arg(long, value_name = "MB")]
+    memory: Option<usize>,
+}
+
+impl LimitArgs {
+    fn limits(&self) -> Limits {
+        Limits {
+            timeout: self.timeout,
+            memory: self.memory.map(|megabytes| megabytes << 20),
+            cancel: None,
+        }
     }
-    let elapsed = start.elapsed() / count;
+}
 
-    sp.stop_with_symbol("✅");
-    println!();
-    println!("Answer for the first part:");
-    println!("--------------------------");
-    println!("{first_answer}");
-    println!("Time elapsed: {elapsed:?}");
-    println!();
-    println!();
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve every puzzle of a year, or of every year, and print a summary table
+    Run(RunArgs),
+
+    /// Benchmark one puzzle, a whole year or every solved puzzle
+    Bench(BenchArgs),
+
+    /// Check the answers of one puzzle, a whole year or every solved puzzle against answers.toml
+    Verify(VerifyArgs),
+
+    /// Print the question of a puzzle from the questions/ archive
+    Show(ShowArgs),
+
+    /// Create and register the module of a new solver
+    New(NewArgs),
+
+    /// Report missing questions and solvers, broken links and examples the solvers get wrong
+    Check,
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// Solve every puzzle of this year
+    #[arg(
+        long,
+        value_parser = year_parser(),
+        required_unless_present = "all",
+        conflicts_with = "all"
+    )]
+    year: Option<u16>,
+
+    /// Solve every puzzle of every year
+    #[arg(long)]
+    all: bool,
+
+    /// Print a table, a JSON array of records or one JSON record per line as puzzles are solved
+    #[arg(long, value_enum, default_value_t = run::Format::Text)]
+    format: run::Format,
+
+    /// Number of parts solved at the same time, the number of CPUs by default
+    #[arg(short, long, value_parser = clap::value_parser!(u64).range(1..))]
+    jobs: Option<u64>,
+
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args, Debug)]
+struct BenchArgs {
+    /// Only benchmark the puzzles of this year
+    #[arg(value_parser = year_parser())]
+    year: Option<u16>,
+
+    /// Only benchmark this day of the year
+    #[arg(value_parser = day_parser(), requires = "year")]
+    day: Option<u8>,
+
+    /// Time spent on each part
+    #[arg(long, value_name = "SECONDS", value_parser = seconds_parser, default_value = "1")]
+    time: Duration,
+
+    /// Report format
+    #[arg(long, value_enum, default_value_t = bench::Format::Table)]
+    format: bench::Format,
+
+    /// Write the report to a file instead of stdout
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args, Debug)]
+struct VerifyArgs {
+    /// Only verify the puzzles of this year
+    #[arg(value_parser = year_parser())]
+    year: Option<u16>,
+
+    /// Only verify this day of the year
+    #[arg(value_parser = day_parser(), requires = "year")]
+    day: Option<u8>,
+
+    #[command(flatten)]
+    limits: LimitArgs,
+}
+
+#[derive(Args, Debug)]
+struct ShowArgs {
+    /// Year of the puzzle
+    #[arg(value_parser = year_parser())]
+    year: u16,
+
+    /// Day of the puzzle
+    #[arg(value_parser = day_parser())]
+    day: u8,
+}
+
+#[derive(Args, Debug)]
+struct NewArgs {
+    /// Year of the puzzle, which doesn't have to be solved yet
+    #[arg(value_parser = year_parser())]
+    year: u16,
 
-    let mut sp = Spinner::new(Spinners::Dots, "Solving the second part...".to_string());
-    let start = Instant::now();
-    let second_answer = solver.solve_second_part();
-    let mut count = 1;
-    while start.elapsed() < Duration::from_secs(1) {
-        solver.solve_second_part();
-        count += 1;
+    /// Day of the puzzle
+    #[arg(value_parser = day_parser())]
+    day: u8,
+
+    /// Title of the puzzle, read from the questions/ archive by default
+    #[arg(long)]
+    title: Option<String>,
+
+    /// Also create a pest grammar to parse the input with
+    #[arg(long)]
+    grammar: bool,
+}
+
+impl Cli {
+    fn read_input(&self) -> io::Result<Option<String>> {
+        match (&self.input, &self.input_text) {
+            (Some(path), _) if path.as_os_str() == "-" => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(Some(input))
+            }
+            (Some(path), _) => fs::read_to_string(path).map(Some),
+            (None, text) => Ok(text.clone()),
+        }
     }
-    let elapsed = start.elapsed() / count;
+}
 
-    sp.stop_with_symbol("✅");
-    println!();
-    println!("Answer for the second part:");
-    println!("---------------------------");
-    println!("{second_answer}");
-    println!("Time elapsed: {elapsed:?}");
+fn describe_progress(update: &ProgressUpdate) -> String {
+    let mut parts = vec![format!("{} states", update.states)];
+    if let Some(best) = update.best {
+        parts.push(format!("best {best}"));
+    }
+    if let Some(iteration) = update.iteration {
+        parts.push(format!("iteration {iteration}"));
+    }
+    parts.join(", ")
+}
+
+/// Solves one part on a worker thread that gives up once `limits` are exceeded, then times it over
+/// `budget` on this thread, so that the limits bound a single solve rather than the benchmark.
+fn measure_part(
+    puzzle: &'static Puzzle,
+    input: Option<&str>,
+    part: Part,
+    budget: Duration,
+    limits: &Limits,
+    report: &dyn Fn(ProgressUpdate) -> bool,
+) -> Result<(Answer, bench::Stats), SolveError> {
+    let solve = move |solver: &dyn Solver<Answer, Answer>, progress: &ProgressHook| match part {
+        Part::First => solver.solve_first_part_with(progress),
+        Part::Second => solver.solve_second_part_with(progress),
+    };
+    let (answer, first) = puzzle.solve_within(
+        input,
+        limits,
+        move |solver, progress| {
+            let start = Instant::now();
+            solve(solver, progress).map(|answer| (answer, start.elapsed()))
+        },
+        report,
+    )?;
+    if first >= budget {
+        return Ok((answer, bench::repeat(first, budget, || {})));
+    }
+    let solver = puzzle
+        .solver(input)
+        .map_err(|error| SolveError::Unsupported(error.to_string()))?;
+    let stats = bench::repeat(first, budget, || {
+        let _ = solve(&*solver, &ProgressHook::none());
+    });
+    Ok((answer, stats))
+}
+
+fn solve_part(
+    puzzle: &'static Puzzle,
+    input: Option<&str>,
+    part: Part,
+    budget: Duration,
+    limits: &Limits,
+) -> bool {
+    let (name, answer_type) = match part {
+        Part::First => ("first", puzzle.answer_types()[0]),
+        Part::Second => ("second", puzzle.answer_types()[1]),
+    };
+    // The spinner and the progress line would only garble the output of a pipe or a file.
+    let terminal = io::stdout().is_terminal();
+    let message = format!("Solving the {name} part...");
+    let spinner = RefCell::new(terminal.then(|| Spinner::new(Spinners::Dots, message.clone())));
+    let report = |update: ProgressUpdate| {
+        // The spinner can't change its message, so the progress line takes its place.
+        if let Some(mut spinner) = spinner.borrow_mut().take() {
+            spinner.stop();
+        }
+        if terminal {
+            eprint!("\x1b[2K\r⏳ {message} {}", describe_progress(&update));
+        }
+        true
+    };
+    let result = measure_part(puzzle, input, part, budget, limits, &report);
+    let symbol = if result.is_ok() { "✅" } else { "❌" };
+    match spinner.into_inner() {
+        Some(mut spinner) => spinner.stop_with_symbol(symbol),
+        None if terminal => eprintln!("\x1b[2K\r{symbol} {message}"),
+        None => {}
+    }
+    if terminal && result.is_ok() {
+        println!();
+    }
+    let (answer, stats) = match result {
+        Ok(measurement) => measurement,
+        Err(error) => {
+            eprintln!("Could not solve the {name} part: {error}");
+            return false;
+        }
+    };
+    let title = format!("Answer for the {name} part ({answer_type}):");
+    println!("{title}");
+    println!("{}", "-".repeat(title.len()));
+    println!("{answer}");
+    // Keep the screen visible in case a letter is misread.
+    if let Answer::Grid {
+        rows,
+        letters: Some(_),
+    } = &answer
+    {
+        println!();
+        rows.iter().for_each(|row| println!("{row}"));
+    }
+    println!("Time elapsed: {:?}", stats.mean);
     println!();
+    true
+}
+
+fn solve(
+    puzzle: &'static Puzzle,
+    input: Option<&str>,
+    part: Option<Part>,
+    budget: Duration,
+    limits: &Limits,
+) -> bool {
+    match part {
+        Some(part) => solve_part(puzzle, input, part, budget, limits),
+        None => {
+            let first = solve_part(puzzle, input, Part::First, budget, limits);
+            println!();
+            let second = solve_part(puzzle, input, Part::Second, budget, limits);
+            first && second
+        }
+    }
+}
+
+/// Solves the selected parts once `budget` is spent on each, as records for `--format json`.
+fn solve_records(
+    puzzle: &'static Puzzle,
+    input: Option<&str>,
+    part: Option<Part>,
+    budget: Duration,
+    limits: &Limits,
+) -> Vec<Value> {
+    [(1, Part::First), (2, Part::Second)]
+        .into_iter()
+        .filter(|(_, selected)| part.is_none() || part == Some(*selected))
+        .map(|(number, selected)| {
+            let result = measure_part(puzzle, input, selected, budget, limits, &|_| true)
+                .map(|(answer, stats)| (answer, stats.mean));
+            run::record(puzzle.year, puzzle.day, number, &result)
+        })
+        .collect()
+}
+
+fn select_puzzles(year: Option<u16>, day: Option<u8>) -> Vec<&'static Puzzle> {
+    let selected: Vec<_> = puzzles()
+        .iter()
+        .filter(|puzzle| year.unwrap_or(puzzle.year) == puzzle.year)
+        .filter(|puzzle| day.unwrap_or(puzzle.day) == puzzle.day)
+        .collect();
+    if selected.is_empty() {
+        match (year, day) {
+            (Some(year), Some(day)) => eprintln!("{}", unsolved(year, day)),
+            (Some(year), None) => eprintln!(
+                "No puzzle of {year} is solved yet. Solved years: {}",
+                list(&years())
+            ),
+            _ => eprintln!("There is no solved puzzle to select"),
+        }
+        process::exit(1);
+    }
+    selected
+}
+
+fn run_bench(args: BenchArgs) {
+    let budget = args.time;
+    let limits = args.limits.limits();
+    let records: Vec<_> = select_puzzles(args.year, args.day)
+        .into_iter()
+        .flat_map(|puzzle| {
+            eprintln!(
+                "Benchmarking {} Day {}: {}",
+                puzzle.year, puzzle.day, puzzle.title
+            );
+            [(1, Part::First), (2, Part::Second)].map(|(number, part)| bench::Record {
+                year: puzzle.year,
+                day: puzzle.day,
+                part: number,
+                result: measure_part(puzzle, None, part, budget, &limits, &|_| true)
+                    .map(|(_, stats)| stats),
+            })
+        })
+        .collect();
+    let report = bench::report(&records, args.format);
+    match args.output {
+        Some(path) => {
+            if let Err(error) = fs::write(&path, report) {
+                eprintln!("Could not write {}: {error}", path.display());
+                process::exit(1);
+            }
+        }
+        None => print!("{report}"),
+    }
+}
+
+fn verify(args: VerifyArgs) {
+    let answers = answers::Answers::default();
+    let limits = args.limits.limits();
+    let mut failures = 0;
+    for puzzle in select_puzzles(args.year, args.day) {
+        for (part, check) in (1..).zip(answers.verify(puzzle, &limits)) {
+            let status = match check {
+                Check::Correct => "✅".to_string(),
+                Check::Wrong { expected, actual } => {
+                    failures += 1;
+                    format!("❌ expected {expected:?}, got {actual:?}")
+                }
+                Check::Missing(actual) => format!("⚠️ no stored answer, got {actual:?}"),
+                Check::Failed(error) => {
+                    failures += 1;
+                    format!("❌ {error}")
+                }
+            };
+            println!("{} Day {} part {part}: {status}", puzzle.year, puzzle.day);
+        }
+    }
+    if failures > 0 {
+        eprintln!("{failures} answers do not match answers.toml");
+        process::exit(1);
+    }
+}
+
+fn run_all(args: RunArgs) {
+    let years = match args.year {
+        Some(year) => vec![year],
+        None => years(),
+    };
+    let limits = args.limits.limits();
+    let days: Vec<_> = years
+        .into_iter()
+        .flat_map(|year| (1..=25).map(move |day| (year, day)))
+        .collect();
+    let jobs = args.jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, usize::from),
+        |jobs| jobs as usize,
+    );
+    let rows = run::run(&days, &limits, jobs, |row| {
+        if args.format == run::Format::Ndjson {
+            print!(
+                "{}",
+                run::report(&run::records(slice::from_ref(row)), args.format)
+            );
+        }
+    });
+    match args.format {
+        run::Format::Text => print!("{}", run::table(&rows)),
+        run::Format::Json => print!("{}", run::report(&run::records(&rows), args.format)),
+        run::Format::Ndjson => {}
+    }
+}
+
+fn show(args: ShowArgs) {
+    let (year, day) = (args.year, args.day);
+    let question = match find_question(year, day) {
+        Some(Ok(question)) => question,
+        Some(Err(error)) => {
+            eprintln!("Could not parse the question of {year} Day {day}: {error}");
+            process::exit(1);
+        }
+        None => {
+            eprintln!("There is no question for {year} Day {day}");
+            process::exit(1);
+        }
+    };
+    println!("{year} Day {day}: {}", question.title);
+    for (index, part) in question.parts.iter().enumerate() {
+        if index > 0 {
+            println!();
+            println!("--- Part Two ---");
+        }
+        println!();
+        println!("{}", part.description);
+    }
+}
+
+fn check() {
+    let issues = check_questions();
+    for issue in &issues {
+        println!("{issue}");
+    }
+    if !issues.is_empty() {
+        eprintln!("Found {} issues in the questions", issues.len());
+        process::exit(1);
+    }
+}
+
+fn new(args: NewArgs) {
+    let (year, day) = (args.year, args.day);
+    let title = args
+        .title
+        .unwrap_or_else(|| match find_question(year, day) {
+            Some(Ok(question)) => question.title,
+            _ => {
+                eprintln!("There is no question for {year} Day {day}, pass its title with --title");
+                process::exit(1);
+            }
+        });
+    let current_dir = env::current_dir().unwrap_or_else(|error| {
+        eprintln!("Could not read the current directory: {error}");
+        process::exit(1);
+    });
+    let Some(root) = scaffold::find_root(&current_dir) else {
+        eprintln!(
+            "Could not find Cargo.toml in {} or its parents, run `new` inside the repository",
+            current_dir.display()
+        );
+        process::exit(1);
+    };
+    match scaffold::create(root, year, day, &title, args.grammar) {
+        Ok(files) => {
+            for file in files {
+                println!("Wrote {}", file.display());
+            }
+        }
+        Err(error) => {
+            eprintln!("Could not create the solver of {year} Day {day}: {error}");
+            process::exit(1);
+        }
+    }
 }
 
 fn main() {
     let args = Cli::parse();
-    match (args.year, args.day) {
-        (Year::Year2015, Day::Day1) => solve(Box::new(Solver2015_01::default())),
-        (Year::Year2015, Day::Day2) => solve(Box::new(Solver2015_02::default())),
-        (Year::Year2015, Day::Day3) => solve(Box::new(Solver2015_03::default())),
-        (Year::Year2015, Day::Day4) => solve(Box::new(Solver2015_04::default())),
-        (Year::Year2015, Day::Day5) => solve(Box::new(Solver2015_05::default())),
-        (Year::Year2015, Day::Day6) => solve(Box::new(Solver2015_06::default())),
-        (Year::Year2015, Day::Day7) => solve(Box::new(Solver2015_07::default())),
-        (Year::Year2015, Day::Day8) => solve(Box::new(Solver2015_08::default())),
-        (Year::Year2015, Day::Day9) => solve(Box::new(Solver2015_09::default())),
-        (Year::Year2015, Day::Day10) => solve(Box::new(Solver2015_10::default())),
-        (Year::Year2015, Day::Day12) => solve(Box::new(Solver2015_12::default())),
-        (Year::Year2015, Day::Day13) => solve(Box::new(Solver2015_13::default())),
-        (Year::Year2015, Day::Day14) => solve(Box::new(Solver2015_14::default())),
-        (Year::Year2015, Day::Day15) => solve(Box::new(Solver2015_15::default())),
-        (Year::Year2015, Day::Day16) => solve(Box::new(Solver2015_16::default())),
-        (Year::Year2015, Day::Day17) => solve(Box::new(Solver2015_17::default())),
-        (Year::Year2015, Day::Day18) => solve(Box::new(Solver2015_18::default())),
-        (Year::Year2015, Day::Day19) => solve(Box::new(Solver2015_19::default())),
-        (Year::Year2022, Day::Day1) => solve(Box::new(Solver2022_01::default())),
-        (Year::Year2022, Day::Day2) => solve(Box::new(Solver2022_02::default())),
-        (Year::Year2022, Day::Day3) => solve(Box::new(Solver2022_03::default())),
-        (Year::Year2022, Day::Day4) => solve(Box::new(Solver2022_04::default())),
-        (Year::Year2022, Day::Day5) => solve(Box::new(Solver2022_05::default())),
-        (Year::Year2022, Day::Day6) => solve(Box::new(Solver2022_06::default())),
-        (Year::Year2022, Day::Day7) => solve(Box::new(Solver2022_07::default())),
-        (Year::Year2022, Day::Day8) => solve(Box::new(Solver2022_08::default())),
-        (Year::Year2022, Day::Day9) => solve(Box::new(Solver2022_09::default())),
-        (Year::Year2022, Day::Day10) => solve(Box::new(Solver2022_10::default())),
-        (Year::Year2022, Day::Day11) => solve(Box::new(Solver2022_11::default())),
-        (Year::Year2022, Day::Day12) => solve(Box::new(Solver2022_12::default())),
-        (Year::Year2022, Day::Day13) => solve(Box::new(Solver2022_13::default())),
-        (Year::Year2022, Day::Day14) => solve(Box::new(Solver2022_14::default())),
-        (Year::Year2022, Day::Day15) => solve(Box::new(Solver2022_15::default())),
-        (Year::Year2022, Day::Day16) => solve(Box::new(Solver2022_16::default())),
-        (Year::Year2022, Day::Day17) => solve(Box::new(Solver2022_17::default())),
-        (Year::Year2022, Day::Day18) => solve(Box::new(Solver2022_18::default())),
-        (Year::Year2022, Day::Day20) => solve(Box::new(Solver2022_20::default())),
-        (Year::Year2022, Day::Day21) => solve(Box::new(Solver2022_21::default())),
-        (Year::Year2022, Day::Day22) => solve(Box::new(Solver2022_22::default())),
-        (Year::Year2024, Day::Day1) => solve(Box::new(Solver2024_01::default())),
-        (Year::Year2024, Day::Day2) => solve(Box::new(Solver2024_02::default())),
-        (Year::Year2024, Day::Day3) => solve(Box::new(Solver2024_03::default())),
-        (Year::Year2024, Day::Day4) => solve(Box::new(Solver2024_04::default())),
-        (Year::Year2024, Day::Day5) => solve(Box::new(Solver2024_05::default())),
-        (Year::Year2024, Day::Day6) => solve(Box::new(Solver2024_06::default())),
-        (Year::Year2024, Day::Day7) => solve(Box::new(Solver2024_07::default())),
-        (Year::Year2024, Day::Day8) => solve(Box::new(Solver2024_08::default())),
-        (Year::Year2024, Day::Day9) => solve(Box::new(Solver2024_09::default())),
-        (Year::Year2024, Day::Day10) => solve(Box::new(Solver2024_10::default())),
-        (Year::Year2024, Day::Day11) => solve(Box::new(Solver2024_11::default())),
-        (Year::Year2024, Day::Day12) => solve(Box::new(Solver2024_12::default())),
-        (Year::Year2024, Day::Day13) => solve(Box::new(Solver2024_13::default())),
-        (Year::Year2024, Day::Day14) => solve(Box::new(Solver2024_14::default())),
-        (Year::Year2024, Day::Day15) => solve(Box::new(Solver2024_15::default())),
-        (Year::Year2024, Day::Day16) => solve(Box::new(Solver2024_16::default())),
-        (Year::Year2024, Day::Day17) => solve(Box::new(Solver2024_17::default())),
-        (Year::Year2024, Day::Day18) => solve(Box::new(Solver2024_18::default())),
-        (Year::Year2024, Day::Day19) => solve(Box::new(Solver2024_19::default())),
-        (Year::Year2024, Day::Day20) => solve(Box::new(Solver2024_20::default())),
-        (Year::Year2024, Day::Day21) => solve(Box::new(Solver2024_21::default())),
-        (Year::Year2024, Day::Day22) => solve(Box::new(Solver2024_22::default())),
-        (Year::Year2024, Day::Day23) => solve(Box::new(Solver2024_23::default())),
-        (Year::Year2024, Day::Day24) => solve(Box::new(Solver2024_24::default())),
-        (Year::Year2024, Day::Day25) => solve(Box::new(Solver2024_25::default())),
-        _ => panic!("Puzzle is not solved yet!"),
+    match args.command {
+        Some(Command::Run(run_args)) => return run_all(run_args),
+        Some(Command::Bench(bench_args)) => return run_bench(bench_args),
+        Some(Command::Verify(verify_args)) => return verify(verify_args),
+        Some(Command::Show(show_args)) => return show(show_args),
+        Some(Command::New(new_args)) => return new(new_args),
+        Some(Command::Check) => return check(),
+        None => {}
+    }
+    let (year, day) = (args.year.unwrap(), args.day.unwrap());
+    let input = args.read_input().unwrap_or_else(|error| {
+        match &args.input {
+            Some(path) if path.as_os_str() == "-" => {
+                eprintln!("Could not read the standard input: {error}")
+            }
+            Some(path) => eprintln!("Could not read {}: {error}", path.display()),
+            None => eprintln!("Could not read the puzzle input: {error}"),
+        }
+        process::exit(1);
+    });
+    match find_puzzle(year, day) {
+        Some(puzzle) => {
+            if let Err(error) = puzzle.solver(input.as_deref()) {
+                eprintln!("Could not parse the puzzle input: {error}");
+                if let Some(line) = input
+                    .as_deref()
+                    .and_then(|input| input.lines().nth(error.line - 1))
+                {
+                    eprintln!("{line}");
+                    eprintln!("{}^", " ".repeat(error.column - 1));
+                }
+                process::exit(1);
+            }
+            let limits = args.limits.limits();
+            let budget = if args.no_bench {
+                Duration::ZERO
+            } else {
+                Duration::from_secs(1)
+            };
+            let solved = match args.format {
+                run::Format::Text => {
+                    println!("{year} Day {day}: {}", puzzle.title);
+                    println!();
+                    solve(puzzle, input.as_deref(), args.part, budget, &limits)
+                }
+                format => {
+                    let records =
+                        solve_records(puzzle, input.as_deref(), args.part, budget, &limits);
+                    print!("{}", run::report(&records, format));
+                    records.iter().all(|record| record.get("error").is_none())
+                }
+            };
+            if !solved {
+                process::exit(1);
+            }
+        }
+        None => {
+            eprintln!("{}", unsolved(year, day));
+            process::exit(1);
+        }
     }
 }
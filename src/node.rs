@@ -1,104 +1,468 @@
 use super::solver::{
-    Solver, Solver2015_01, Solver2015_02, Solver2015_03, Solver2015_04, Solver2015_05,
-    Solver2015_06, Solver2015_07, Solver2015_08, Solver2015_09, Solver2015_10, Solver2015_12,
-    Solver2015_13, Solver2015_14, Solver2015_15, Solver2015_16, Solver2015_17, Solver2015_18,
-    Solver2015_19, Solver2022_01, Solver2022_02, Solver2022_03, Solver2022_04, Solver2022_05,
-    Solver2022_06, Solver2022_07, Solver2022_08, Solver2022_09, Solver2022_10, Solver2022_11,
-    Solver2022_12, Solver2022_13, Solver2022_14, Solver2022_15, Solver2022_16, Solver2022_17,
-    Solver2022_18, Solver2022_20, Solver2022_21, Solver2022_22, Solver2024_01, Solver2024_02,
-    Solver2024_03, Solver2024_04, Solver2024_05, Solver2024_06, Solver2024_07, Solver2024_08,
-    Solver2024_09, Solver2024_10, Solver2024_11, Solver2024_12, Solver2024_13, Solver2024_14,
-    Solver2024_15, Solver2024_16, Solver2024_17, Solver2024_18, Solver2024_19, Solver2024_20,
-    Solver2024_21, Solver2024_22, Solver2024_23, Solver2024_24, Solver2024_25,
+    find_puzzle, find_question, puzzles, unsolved, Answer, Limits, ProgressUpdate, Puzzle, Snafu,
+    SolveError,
 };
+use napi::bindgen_prelude::{AsyncTask, BigInt};
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
+use napi::{Env, Error, JsFunction, JsObject, JsUnknown, Ref, Result, Status, Task};
 use napi_derive::napi;
-use std::fmt::Display;
+use std::fs;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::Duration;
+
+#[napi(string_enum)]
+pub enum AnswerKind {
+    Integer,
+    BigInteger,
+    Text,
+    Grid,
+    List,
+}
+
+/// An answer with `text` as it would be submitted and the field matching its `kind` set.
+#[napi(object)]
+pub struct TypedAnswer {
+    pub kind: AnswerKind,
+    pub text: String,
+    pub integer: Option<i64>,
+    pub big_integer: Option<BigInt>,
+    pub grid: Option<Vec<String>>,
+    pub list: Option<Vec<TypedAnswer>>,
+}
+
+impl From<Answer> for TypedAnswer {
+    fn from(answer: Answer) -> Self {
+        let mut typed = TypedAnswer {
+            kind: AnswerKind::Text,
+            text: answer.to_string(),
+            integer: None,
+            big_integer: None,
+            grid: None,
+            list: None,
+        };
+        match answer {
+            Answer::Integer(value) => {
+                typed.kind = AnswerKind::Integer;
+                typed.integer = Some(value);
+            }
+            Answer::BigInteger(value) => {
+                typed.kind = AnswerKind::BigInteger;
+                typed.big_integer = Some(BigInt::from(value));
+            }
+            Answer::Text(_) => {}
+            Answer::Grid { rows, .. } => {
+                typed.kind = AnswerKind::Grid;
+                typed.grid = Some(rows);
+            }
+            Answer::List(items) => {
+                typed.kind = AnswerKind::List;
+                typed.list = Some(items.into_iter().map(TypedAnswer::from).collect());
+            }
+        }
+        typed
+    }
+}
 
 #[napi(object)]
 pub struct Answers {
-    pub first: String,
-    pub second: String,
+    pub title: String,
+    pub first: TypedAnswer,
+    pub second: TypedAnswer,
 }
 
-impl<T1: Display, T2: Display> From<Box<dyn Solver<T1, T2>>> for Answers {
-    fn from(solver: Box<dyn Solver<T1, T2>>) -> Self {
+/// Marks the start and the end of each part, and in between the progress of the slow solvers.
+#[napi(object)]
+pub struct Progress {
+    pub part: u32,
+    pub finished: bool,
+    pub states: Option<i64>,
+    pub best: Option<i64>,
+    pub iteration: Option<i64>,
+}
+
+impl Progress {
+    fn new(part: u32, finished: bool) -> Self {
+        Self {
+            part,
+            finished,
+            states: None,
+            best: None,
+            iteration: None,
+        }
+    }
+
+    fn update(part: u32, update: ProgressUpdate) -> Self {
         Self {
-            first: format!("{}", solver.solve_first_part()),
-            second: format!("{}", solver.solve_second_part()),
+            states: Some(update.states as i64),
+            best: update.best,
+            iteration: update.iteration.map(|iteration| iteration as i64),
+            ..Self::new(part, false)
+        }
+    }
+}
+
+/// Bounds each part, which fails once it runs longer than `timeout_ms` or grows the heap of its
+/// thread by more than `memory_mb`.
+///
+/// Solvers that don't report their progress can't be stopped: a part that fails this way leaks its
+/// thread, a CPU core and its heap in the Node process until the solver finishes on its own.
+#[napi(object)]
+#[derive(Default)]
+pub struct SolveLimits {
+    pub timeout_ms: Option<u32>,
+    pub memory_mb: Option<u32>,
+}
+
+impl From<SolveLimits> for Limits {
+    fn from(limits: SolveLimits) -> Self {
+        Limits {
+            timeout: limits.timeout_ms.map(|ms| Duration::from_millis(ms.into())),
+            memory: limits.memory_mb.map(|mb| (mb as usize) << 20),
+            cancel: None,
         }
     }
 }
 
+impl Answers {
+    /// Calls `on_progress` around and during each part, which may stop the solver by failing.
+    fn new(
+        puzzle: &'static Puzzle,
+        input: Option<&str>,
+        limits: &Limits,
+        on_progress: impl Fn(Progress) -> Result<()>,
+    ) -> Result<Self> {
+        puzzle.solver(input).map_err(|error| {
+            Error::from_reason(format!("Could not parse the puzzle input: {error}"))
+        })?;
+        let solve_error = |part, error| match error {
+            SolveError::Cancelled => abort_error(),
+            error => Error::from_reason(format!("Could not solve the {part} part: {error}")),
+        };
+        let on_progress = &on_progress;
+        let report = |part| move |update| on_progress(Progress::update(part, update)).is_ok();
+        on_progress(Progress::new(1, false))?;
+        let first = puzzle
+            .solve_within(
+                input,
+                limits,
+                |solver, progress| solver.solve_first_part_with(progress),
+                &report(1),
+            )
+            .map_err(|error| solve_error("first", error))?;
+        on_progress(Progress::new(1, true))?;
+        on_progress(Progress::new(2, false))?;
+        let second = puzzle
+            .solve_within(
+                input,
+                limits,
+                |solver, progress| solver.solve_second_part_with(progress),
+                &report(2),
+            )
+            .map_err(|error| solve_error("second", error))?;
+        on_progress(Progress::new(2, true))?;
+        Ok(Self {
+            title: puzzle.title.to_string(),
+            first: first.into(),
+            second: second.into(),
+        })
+    }
+}
+
+fn find(year: u32, day: u32) -> Result<&'static Puzzle> {
+    let (year, day) = u16::try_from(year)
+        .ok()
+        .zip(u8::try_from(day).ok())
+        .ok_or_else(|| Error::from_reason(format!("There is no puzzle {year}/{day}")))?;
+    find_puzzle(year, day).ok_or_else(|| Error::from_reason(unsolved(year, day)))
+}
+
+#[napi(object)]
+pub struct PuzzleInfo {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+}
+
+#[napi]
+pub fn list_puzzles() -> Vec<PuzzleInfo> {
+    puzzles()
+        .iter()
+        .map(|puzzle| PuzzleInfo {
+            year: puzzle.year.into(),
+            day: puzzle.day.into(),
+            title: puzzle.title.to_string(),
+        })
+        .collect()
+}
+
+#[napi]
+pub fn solve(
+    year: u32,
+    day: u32,
+    input: Option<String>,
+    limits: Option<SolveLimits>,
+) -> Result<Answers> {
+    let puzzle = find(year, day)?;
+    let limits = limits.unwrap_or_default().into();
+    Answers::new(puzzle, input.as_deref(), &limits, |_| Ok(()))
+}
+
+#[napi]
+pub fn solve_file(
+    year: u32,
+    day: u32,
+    path: String,
+    limits: Option<SolveLimits>,
+) -> Result<Answers> {
+    let puzzle = find(year, day)?;
+    let input = fs::read_to_string(&path)
+        .map_err(|error| Error::from_reason(format!("Could not read {path}: {error}")))?;
+    let limits = limits.unwrap_or_default().into();
+    Answers::new(puzzle, Some(&input), &limits, |_| Ok(()))
+}
+
+fn parse_snafu(text: &str) -> Result<Snafu> {
+    text.parse()
+        .map_err(|error| Error::from_reason(format!("Invalid SNAFU number {text}: {error}")))
+}
+
+/// Reads a SNAFU number of the 2022 day 25 puzzle, such as `2=-1=0`.
 #[napi]
-pub fn solve(year: u32, day: u32) -> Answers {
-    match (year, day) {
-        (2015, 1) => Answers::from(Box::new(Solver2015_01::default()) as Box<dyn Solver<_, _>>),
-        (2015, 2) => Answers::from(Box::new(Solver2015_02::default()) as Box<dyn Solver<_, _>>),
-        (2015, 3) => Answers::from(Box::new(Solver2015_03::default()) as Box<dyn Solver<_, _>>),
-        (2015, 4) => Answers::from(Box::new(Solver2015_04::default()) as Box<dyn Solver<_, _>>),
-        (2015, 5) => Answers::from(Box::new(Solver2015_05::default()) as Box<dyn Solver<_, _>>),
-        (2015, 6) => Answers::from(Box::new(Solver2015_06::default()) as Box<dyn Solver<_, _>>),
-        (2015, 7) => Answers::from(Box::new(Solver2015_07::default()) as Box<dyn Solver<_, _>>),
-        (2015, 8) => Answers::from(Box::new(Solver2015_08::default()) as Box<dyn Solver<_, _>>),
-        (2015, 9) => Answers::from(Box::new(Solver2015_09::default()) as Box<dyn Solver<_, _>>),
-        (2015, 10) => Answers::from(Box::new(Solver2015_10::default()) as Box<dyn Solver<_, _>>),
-        (2015, 12) => Answers::from(Box::new(Solver2015_12::default()) as Box<dyn Solver<_, _>>),
-        (2015, 13) => Answers::from(Box::new(Solver2015_13::default()) as Box<dyn Solver<_, _>>),
-        (2015, 14) => Answers::from(Box::new(Solver2015_14::default()) as Box<dyn Solver<_, _>>),
-        (2015, 15) => Answers::from(Box::new(Solver2015_15::default()) as Box<dyn Solver<_, _>>),
-        (2015, 16) => Answers::from(Box::new(Solver2015_16::default()) as Box<dyn Solver<_, _>>),
-        (2015, 17) => Answers::from(Box::new(Solver2015_17::default()) as Box<dyn Solver<_, _>>),
-        (2015, 18) => Answers::from(Box::new(Solver2015_18::default()) as Box<dyn Solver<_, _>>),
-        (2015, 19) => Answers::from(Box::new(Solver2015_19::default()) as Box<dyn Solver<_, _>>),
-        (2022, 1) => Answers::from(Box::new(Solver2022_01::default()) as Box<dyn Solver<_, _>>),
-        (2022, 2) => Answers::from(Box::new(Solver2022_02::default()) as Box<dyn Solver<_, _>>),
-        (2022, 3) => Answers::from(Box::new(Solver2022_03::default()) as Box<dyn Solver<_, _>>),
-        (2022, 4) => Answers::from(Box::new(Solver2022_04::default()) as Box<dyn Solver<_, _>>),
-        (2022, 5) => Answers::from(Box::new(Solver2022_05::default()) as Box<dyn Solver<_, _>>),
-        (2022, 6) => Answers::from(Box::new(Solver2022_06::default()) as Box<dyn Solver<_, _>>),
-        (2022, 7) => Answers::from(Box::new(Solver2022_07::default()) as Box<dyn Solver<_, _>>),
-        (2022, 8) => Answers::from(Box::new(Solver2022_08::default()) as Box<dyn Solver<_, _>>),
-        (2022, 9) => Answers::from(Box::new(Solver2022_09::default()) as Box<dyn Solver<_, _>>),
-        (2022, 10) => Answers::from(Box::new(Solver2022_10::default()) as Box<dyn Solver<_, _>>),
-        (2022, 11) => Answers::from(Box::new(Solver2022_11::default()) as Box<dyn Solver<_, _>>),
-        (2022, 12) => Answers::from(Box::new(Solver2022_12::default()) as Box<dyn Solver<_, _>>),
-        (2022, 13) => Answers::from(Box::new(Solver2022_13::default()) as Box<dyn Solver<_, _>>),
-        (2022, 14) => Answers::from(Box::new(Solver2022_14::default()) as Box<dyn Solver<_, _>>),
-        (2022, 15) => Answers::from(Box::new(Solver2022_15::default()) as Box<dyn Solver<_, _>>),
-        (2022, 16) => Answers::from(Box::new(Solver2022_16::default()) as Box<dyn Solver<_, _>>),
-        (2022, 17) => Answers::from(Box::new(Solver2022_17::default()) as Box<dyn Solver<_, _>>),
-        (2022, 18) => Answers::from(Box::new(Solver2022_18::default()) as Box<dyn Solver<_, _>>),
-        (2022, 20) => Answers::from(Box::new(Solver2022_20::default()) as Box<dyn Solver<_, _>>),
-        (2022, 21) => Answers::from(Box::new(Solver2022_21::default()) as Box<dyn Solver<_, _>>),
-        (2022, 22) => Answers::from(Box::new(Solver2022_22::default()) as Box<dyn Solver<_, _>>),
-        (2024, 1) => Answers::from(Box::new(Solver2024_01::default()) as Box<dyn Solver<_, _>>),
-        (2024, 2) => Answers::from(Box::new(Solver2024_02::default()) as Box<dyn Solver<_, _>>),
-        (2024, 3) => Answers::from(Box::new(Solver2024_03::default()) as Box<dyn Solver<_, _>>),
-        (2024, 4) => Answers::from(Box::new(Solver2024_04::default()) as Box<dyn Solver<_, _>>),
-        (2024, 5) => Answers::from(Box::new(Solver2024_05::default()) as Box<dyn Solver<_, _>>),
-        (2024, 6) => Answers::from(Box::new(Solver2024_06::default()) as Box<dyn Solver<_, _>>),
-        (2024, 7) => Answers::from(Box::new(Solver2024_07::default()) as Box<dyn Solver<_, _>>),
-        (2024, 8) => Answers::from(Box::new(Solver2024_08::default()) as Box<dyn Solver<_, _>>),
-        (2024, 9) => Answers::from(Box::new(Solver2024_09::default()) as Box<dyn Solver<_, _>>),
-        (2024, 10) => Answers::from(Box::new(Solver2024_10::default()) as Box<dyn Solver<_, _>>),
-        (2024, 11) => Answers::from(Box::new(Solver2024_11::default()) as Box<dyn Solver<_, _>>),
-        (2024, 12) => Answers::from(Box::new(Solver2024_12::default()) as Box<dyn Solver<_, _>>),
-        (2024, 13) => Answers::from(Box::new(Solver2024_13::default()) as Box<dyn Solver<_, _>>),
-        (2024, 14) => Answers::from(Box::new(Solver2024_14::default()) as Box<dyn Solver<_, _>>),
-        (2024, 15) => Answers::from(Box::new(Solver2024_15::default()) as Box<dyn Solver<_, _>>),
-        (2024, 16) => Answers::from(Box::new(Solver2024_16::default()) as Box<dyn Solver<_, _>>),
-        (2024, 17) => Answers::from(Box::new(Solver2024_17::default()) as Box<dyn Solver<_, _>>),
-        (2024, 18) => Answers::from(Box::new(Solver2024_18::default()) as Box<dyn Solver<_, _>>),
-        (2024, 19) => Answers::from(Box::new(Solver2024_19::default()) as Box<dyn Solver<_, _>>),
-        (2024, 20) => Answers::from(Box::new(Solver2024_20::default()) as Box<dyn Solver<_, _>>),
-        (2024, 21) => Answers::from(Box::new(Solver2024_21::default()) as Box<dyn Solver<_, _>>),
-        (2024, 22) => Answers::from(Box::new(Solver2024_22::default()) as Box<dyn Solver<_, _>>),
-        (2024, 23) => Answers::from(Box::new(Solver2024_23::default()) as Box<dyn Solver<_, _>>),
-        (2024, 24) => Answers::from(Box::new(Solver2024_24::default()) as Box<dyn Solver<_, _>>),
-        (2024, 25) => Answers::from(Box::new(Solver2024_25::default()) as Box<dyn Solver<_, _>>),
-        _ => Answers {
-            first: "hello".to_string(),
-            second: "bar".to_string(),
-        },
+pub fn snafu_to_number(text: String) -> Result<i64> {
+    i64::try_from(&parse_snafu(&text)?)
+        .map_err(|error| Error::from_reason(format!("Could not convert {text}: {error}")))
+}
+
+#[napi]
+pub fn snafu_to_big_int(text: String) -> Result<BigInt> {
+    // `BigInt::from` can't negate `i128::MIN`, so the words are built here instead.
+    i128::try_from(&parse_snafu(&text)?)
+        .map(|value| {
+            let magnitude = value.unsigned_abs();
+            BigInt {
+                sign_bit: value < 0,
+                words: vec![magnitude as u64, (magnitude >> 64) as u64],
+            }
+        })
+        .map_err(|error| Error::from_reason(format!("Could not convert {text}: {error}")))
+}
+
+#[napi]
+pub fn number_to_snafu(value: i64) -> String {
+    Snafu::from(value).to_string()
+}
+
+#[napi]
+pub fn big_int_to_snafu(value: BigInt) -> Result<String> {
+    // `BigInt::get_i128` ignores the sign of single words, so the words are read here instead.
+    let magnitude = match value.words[..] {
+        [low] => Some(low as u128),
+        [low, high] => Some((high as u128) << 64 | low as u128),
+        _ => None,
+    };
+    magnitude
+        .and_then(|magnitude| match value.sign_bit {
+            false => i128::try_from(magnitude).ok(),
+            true => 0i128.checked_sub_unsigned(magnitude),
+        })
+        .map(|value| Snafu::from(value).to_string())
+        .ok_or_else(|| Error::from_reason("The number does not fit in 128 bits"))
+}
+
+#[napi(object)]
+pub struct QuestionPart {
+    pub description: String,
+    pub examples: Vec<String>,
+}
+
+#[napi(object)]
+pub struct Question {
+    pub year: u32,
+    pub day: u32,
+    pub title: String,
+    pub parts: Vec<QuestionPart>,
+    pub links: Vec<String>,
+}
+
+#[napi]
+pub fn get_question(year: u32, day: u32) -> Result<Question> {
+    let question = u16::try_from(year)
+        .ok()
+        .zip(u8::try_from(day).ok())
+        .and_then(|(year, day)| find_question(year, day))
+        .ok_or_else(|| Error::from_reason(format!("There is no question for {year}/{day}")))?
+        .map_err(|error| {
+            Error::from_reason(format!(
+                "Could not parse the question of {year}/{day}: {error}"
+            ))
+        })?;
+    Ok(Question {
+        year,
+        day,
+        title: question.title,
+        parts: question
+            .parts
+            .into_iter()
+            .map(|part| QuestionPart {
+                description: part.description,
+                examples: part.examples,
+            })
+            .collect(),
+        links: question.links,
+    })
+}
+
+fn abort_error() -> Error {
+    Error::new(Status::Cancelled, "AbortError".to_string())
+}
+
+pub struct SolveTask {
+    puzzle: &'static Puzzle,
+    input: Option<String>,
+    limits: Limits,
+    cancelled: Arc<AtomicBool>,
+    abort_listener: Option<AbortListener>,
+    on_progress: Option<ThreadsafeFunction<Progress, ErrorStrategy::Fatal>>,
+}
+
+impl Task for SolveTask {
+    type Output = Answers;
+    type JsValue = Answers;
+
+    fn compute(&mut self) -> Result<Self::Output> {
+        Answers::new(
+            self.puzzle,
+            self.input.as_deref(),
+            &self.limits,
+            |progress| {
+                if self.cancelled.load(Ordering::Relaxed) {
+                    return Err(abort_error());
+                }
+                let Some(on_progress) = &self.on_progress else {
+                    return Ok(());
+                };
+                if progress.states.is_some() {
+                    on_progress.call(progress, ThreadsafeFunctionCallMode::NonBlocking);
+                    return Ok(());
+                }
+                // Waiting for the start and the end of each part to be delivered also flushes the
+                // updates queued before them, so that none arrives once the promise is settled.
+                let (delivered, wait) = mpsc::channel();
+                let status = on_progress.call_with_return_value(
+                    progress,
+                    ThreadsafeFunctionCallMode::Blocking,
+                    move |_: JsUnknown| {
+                        let _ = delivered.send(());
+                        Ok(())
+                    },
+                );
+                if status == Status::Ok {
+                    // The sender is dropped without a message if the callback throws.
+                    let _ = wait.recv();
+                }
+                Ok(())
+            },
+        )
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> Result<Self::JsValue> {
+        Ok(output)
+    }
+
+    // Runs after both `resolve` and `reject`, so that a reused signal doesn't collect listeners.
+    fn finally(&mut self, env: Env) -> Result<()> {
+        match self.abort_listener.take() {
+            Some(listener) => listener.remove(env),
+            None => Ok(()),
+        }
+    }
+}
+
+/// The `abort` listener of a signal, which holds on to both until it is removed.
+struct AbortListener {
+    signal: Ref<()>,
+    listener: Ref<()>,
+}
+
+impl AbortListener {
+    fn remove(mut self, env: Env) -> Result<()> {
+        let signal: JsObject = env.get_reference_value(&self.signal)?;
+        let listener: JsFunction = env.get_reference_value(&self.listener)?;
+        let remove_event_listener: JsFunction = signal.get_named_property("removeEventListener")?;
+        remove_event_listener.call(
+            Some(&signal),
+            &[
+                env.create_string("abort")?.into_unknown(),
+                listener.into_unknown(),
+            ],
+        )?;
+        self.signal.unref(env)?;
+        self.listener.unref(env)?;
+        Ok(())
+    }
+}
+
+/// Sets the returned flag once `signal` aborts, with the listener to remove once the task is done.
+fn watch_signal(
+    env: &Env,
+    signal: Option<JsObject>,
+) -> Result<(Arc<AtomicBool>, Option<AbortListener>)> {
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let Some(signal) = signal else {
+        return Ok((cancelled, None));
+    };
+    if signal.get_named_property::<bool>("aborted")? {
+        return Err(abort_error());
     }
+    let flag = cancelled.clone();
+    let listener = env.create_function_from_closure("onAbort", move |_| {
+        flag.store(true, Ordering::Relaxed);
+        Ok(())
+    })?;
+    let abort_listener = AbortListener {
+        signal: env.create_reference(&signal)?,
+        listener: env.create_reference(&listener)?,
+    };
+    let mut options = env.create_object()?;
+    options.set_named_property("once", env.get_boolean(true)?)?;
+    let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+    add_event_listener.call(
+        Some(&signal),
+        &[
+            env.create_string("abort")?.into_unknown(),
+            listener.into_unknown(),
+            options.into_unknown(),
+        ],
+    )?;
+    Ok((cancelled, Some(abort_listener)))
+}
+
+#[napi]
+pub fn solve_async(
+    env: Env,
+    year: u32,
+    day: u32,
+    input: Option<String>,
+    #[napi(ts_arg_type = "AbortSignal | undefined | null")] signal: Option<JsObject>,
+    #[napi(ts_arg_type = "((progress: Progress) => void) | undefined | null")] on_progress: Option<
+        ThreadsafeFunction<Progress, ErrorStrategy::Fatal>,
+    >,
+    limits: Option<SolveLimits>,
+) -> Result<AsyncTask<SolveTask>> {
+    let puzzle = find(year, day)?;
+    let (cancelled, abort_listener) = watch_signal(&env, signal)?;
+    // The runner polls the flag, so that solvers which never report their progress stop as well.
+    let limits = Limits {
+        cancel: Some(cancelled.clone()),
+        ..limits.unwrap_or_default().into()
+    };
+    Ok(AsyncTask::new(SolveTask {
+        puzzle,
+        input,
+        limits,
+        cancelled,
+        abort_listener,
+        on_progress,
+    }))
 }
@@ -0,0 +1,288 @@
+use crate::bench;
+use crate::solver::{find_puzzle, Answer, Limits, ProgressHook, Puzzle, SolveError, Solver};
+use clap::ValueEnum;
+use serde_json::{json, Value};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+pub type Part = Result<(Answer, Duration), SolveError>;
+
+pub struct Row {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Option<[Part; 2]>,
+}
+
+fn solve_part(puzzle: &'static Puzzle, part: u8, limits: &Limits) -> Part {
+    let solve = move |solver: &dyn Solver<Answer, Answer>, progress: &ProgressHook| {
+        bench::time_once(|| match part {
+            1 => solver.solve_first_part_with(progress),
+            _ => solver.solve_second_part_with(progress),
+        })
+    };
+    puzzle.solve_within(None, limits, solve, &|_| true)
+}
+
+/// Solves both parts of the puzzles of `days` on `jobs` threads, and passes the rows to `on_row`
+/// in order as soon as they and the rows before them are done.
+pub fn run(
+    days: &[(u16, u8)],
+    limits: &Limits,
+    jobs: usize,
+    mut on_row: impl FnMut(&Row),
+) -> Vec<Row> {
+    let tasks: Vec<(usize, &'static Puzzle, u8)> = days
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &(year, day))| Some((index, find_puzzle(year, day)?)))
+        .flat_map(|(index, puzzle)| [1, 2].map(|part| (index, puzzle, part)))
+        .collect();
+    let mut parts: Vec<[Option<Part>; 2]> = days.iter().map(|_| [None, None]).collect();
+    let mut rows: Vec<Row> = Vec::with_capacity(days.len());
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    thread::scope(|scope| {
+        let (tasks, next) = (&tasks, &next);
+        for _ in 0..jobs.clamp(1, tasks.len().max(1)) {
+            let sender = sender.clone();
+            scope.spawn(move || {
+                while let Some(&(index, puzzle, part)) =
+                    tasks.get(next.fetch_add(1, Ordering::Relaxed))
+                {
+                    if part == 1 {
+                        eprintln!("Solving {} Day {}...", puzzle.year, puzzle.day);
+                    }
+                    let result = solve_part(puzzle, part, limits);
+                    if sender.send((index, part, result)).is_err() {
+                        return;
+                    }
+                }
+            });
+        }
+        drop(sender);
+        let mut flush = |parts: &mut Vec<[Option<Part>; 2]>| {
+            while let Some(&(year, day)) = days.get(rows.len()) {
+                let row = match &mut parts[rows.len()] {
+                    _ if find_puzzle(year, day).is_none() => Row {
+                        year,
+                        day,
+                        parts: None,
+                    },
+                    [first @ Some(_), second @ Some(_)] => Row {
+                        year,
+                        day,
+                        parts: first
+                            .take()
+                            .zip(second.take())
+                            .map(|(first, second)| [first, second]),
+                    },
+                    _ => return,
+                };
+                on_row(&row);
+                rows.push(row);
+            }
+        };
+        flush(&mut parts);
+        for (index, part, result) in receiver {
+            parts[index][usize::from(part) - 1] = Some(result);
+            flush(&mut parts);
+        }
+    });
+    rows
+}
+
+fn cells(part: &Part) -> [String; 2] {
+    match part {
+        Ok((answer, elapsed)) => [
+            answer.to_string().trim_end().replace('\n', " | "),
+            format!("{elapsed:?}"),
+        ],
+        Err(error) => [format!("error: {error}"), String::new()],
+    }
+}
+
+pub fn table(rows: &[Row]) -> String {
+    let mut lines = vec![["Year", "Day", "Part 1", "Time", "Part 2", "Time"]
+        .map(String::from)
+        .to_vec()];
+    for row in rows {
+        let mut line = vec![row.year.to_string(), row.day.to_string()];
+        match &row.parts {
+            Some([first, second]) => {
+                line.extend(cells(first));
+                line.extend(cells(second));
+            }
+            None => line.push("skipped".to_string()),
+        }
+        lines.push(line);
+    }
+    let widths: Vec<usize> = (0..6)
+        .map(|column| {
+            lines
+                .iter()
+                .map(|line| line.get(column).map_or(0, |cell| cell.chars().count()))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut table = String::new();
+    for line in lines {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        table += cells.join("  ").trim_end();
+        table += "\n";
+    }
+    let skipped = rows.iter().filter(|row| row.parts.is_none()).count();
+    table
+        + &format!(
+            "Solved {} puzzles, skipped {skipped}\n",
+            rows.len() - skipped
+        )
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+    Ndjson,
+}
+
+pub fn record(year: u16, day: u8, part: u8, result: &Part) -> Value {
+    match result {
+        Ok((answer, elapsed)) => json!({
+            "year": year,
+            "day": day,
+            "part": part,
+            "answer": answer.to_json(),
+            "time_ns": elapsed.as_nanos() as u64,
+        }),
+        Err(error) => json!({
+            "year": year,
+            "day": day,
+            "part": part,
+            "error": error.to_string(),
+        }),
+    }
+}
+
+/// The records of every solved part, skipping the days without a solver.
+pub fn records(rows: &[Row]) -> Vec<Value> {
+    rows.iter()
+        .filter_map(|row| Some((row, row.parts.as_ref()?)))
+        .flat_map(|(row, parts)| {
+            (1..)
+                .zip(parts)
+                .map(|(part, result)| record(row.year, row.day, part, result))
+        })
+        .collect()
+}
+
+/// Writes `records` as a JSON array or as one JSON object per line.
+pub fn report(records: &[Value], format: Format) -> String {
+    match format {
+        Format::Json => serde_json::to_string_pretty(records).unwrap() + "\n",
+        Format::Ndjson => records
+            .iter()
+            .map(|record| record.to_string() + "\n")
+            .collect(),
+        Format::Text => unreachable!("the text output is not made of records"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_list_missing_days_as_skipped() {
+        let rows = [
+            Row {
+                year: 2015,
+                day: 1,
+                parts: Some([
+                    Ok((Answer::Integer(-1), Duration::from_micros(3))),
+                    Err(SolveError::NoSolution),
+                ]),
+            },
+            Row {
+                year: 2015,
+                day: 20,
+                parts: None,
+            },
+        ];
+        assert_eq!(
+            table(&rows),
+            "\
+Year  Day  Part 1   Time  Part 2                                            Time
+2015  1    -1       3µs   error: the puzzle has no solution for this input
+2015  20   skipped
+Solved 1 puzzles, skipped 1
+"
+        );
+    }
+
+    #[test]
+    fn should_judge_memory_the_same_for_any_job_count() {
+        let days = [(2015, 3), (2022, 1), (2015, 3), (2022, 1)];
+        let limits = Limits {
+            timeout: None,
+            memory: Some(16 << 10),
+            cancel: None,
+        };
+        let over_budget = |jobs| -> Vec<bool> {
+            run(&days, &limits, jobs, |_| {})
+                .iter()
+                .flat_map(|row| row.parts.as_ref().unwrap())
+                .map(|part| matches!(part, Err(SolveError::OutOfMemory(_))))
+                .collect()
+        };
+        let verdicts = [true, true, false, false, true, true, false, false];
+        assert_eq!(over_budget(1), verdicts);
+        assert_eq!(over_budget(4), verdicts);
+    }
+
+    #[test]
+    fn should_keep_rows_in_order() {
+        let days = [(2015, 2), (2015, 20), (2015, 1)];
+        let mut emitted = vec![];
+        let rows = run(&days, &Limits::default(), 4, |row| {
+            emitted.push((row.year, row.day))
+        });
+        assert_eq!(emitted, days);
+        assert!(rows[1].parts.is_none());
+        let [first, second] = rows[2].parts.as_ref().unwrap();
+        assert_eq!(first.as_ref().unwrap().0, Answer::Integer(232));
+        assert_eq!(second.as_ref().unwrap().0, Answer::Integer(1783));
+    }
+
+    #[test]
+    fn should_report_ndjson_records() {
+        let rows = [
+            Row {
+                year: 2015,
+                day: 1,
+                parts: Some([
+                    Ok((Answer::Integer(-1), Duration::from_nanos(3))),
+                    Err(SolveError::NoSolution),
+                ]),
+            },
+            Row {
+                year: 2015,
+                day: 20,
+                parts: None,
+            },
+        ];
+        assert_eq!(
+            report(&records(&rows), Format::Ndjson),
+            "\
+{\"answer\":{\"type\":\"integer\",\"value\":-1},\"day\":1,\"part\":1,\"time_ns\":3,\"year\":2015}
+{\"day\":1,\"error\":\"the puzzle has no solution for this input\",\"part\":2,\"year\":2015}
+"
+        );
+    }
+}
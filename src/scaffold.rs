@@ -0,0 +1,328 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+
+fn module_name(year: u16, day: u8) -> String {
+    format!("solver_{year}_{day:02}")
+}
+
+fn solver_name(year: u16, day: u8) -> String {
+    format!("Solver{year}_{day:02}")
+}
+
+fn solver_module(year: u16, day: u8, grammar: bool) -> String {
+    let solver = solver_name(year, day);
+    let (imports, parser, parse) = if grammar {
+        (
+            "use super::{ParseError, SolveError, Solver};\nuse pest::Parser;\n",
+            format!(
+                r#"
+#[derive(pest_derive::Parser)]
+#[grammar = "solver/{}/grammar.pest"]
+struct InputParser;
+"#,
+                module_name(year, day)
+            ),
+            r#"let input = InputParser::parse(Rule::input, input)?.next().unwrap();
+        Ok(Self {
+            lines: input
+                .into_inner()
+                .filter(|pair| pair.as_rule() == Rule::line)
+                .map(|line| line.as_str().to_string())
+                .collect(),
+        })"#,
+        )
+    } else {
+        (
+            "use super::{ParseError, SolveError, Solver};\n",
+            String::new(),
+            r#"Ok(Self {
+            lines: input.lines().map(String::from).collect(),
+        })"#,
+        )
+    };
+    format!(
+        "{imports}{parser}
+pub struct {solver} {{
+    lines: Vec<String>,
+}}
+
+impl Default for {solver} {{
+    fn default() -> Self {{
+        Self::try_from(include_str!(\"input.txt\")).unwrap()
+    }}
+}}
+
+impl TryFrom<&str> for {solver} {{
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {{
+        {parse}
+    }}
+}}
+
+impl Solver<usize, usize> for {solver} {{
+    fn solve_first_part(&self) -> Result<usize, SolveError> {{
+        Ok(self.lines.len())
+    }}
+
+    fn solve_second_part(&self) -> Result<usize, SolveError> {{
+        Ok(self.lines.len())
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!(\"example.txt\");
+
+    #[test]
+    fn should_solve_first_part_example() {{
+        let solver = {solver}::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 0);
+    }}
+
+    #[test]
+    fn should_solve_second_part_example() {{
+        let solver = {solver}::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 0);
+    }}
+}}
+"
+    )
+}
+
+const GRAMMAR: &str = "\
+input = { SOI ~ (line ~ (NEWLINE ~ line)*)? ~ EOI }
+line = { (!NEWLINE ~ ANY)+ }
+";
+
+/// Why a puzzle can't be added to the `puzzles!` table of the registry.
+#[derive(Debug, PartialEq)]
+pub enum RegistryError {
+    Duplicate(u16, u8),
+    Unrecognised(String),
+}
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RegistryError::Duplicate(year, day) => {
+                write!(f, "{year} Day {day} is already registered")
+            }
+            RegistryError::Unrecognised(reason) => {
+                write!(f, "unrecognised puzzle registry: {reason}")
+            }
+        }
+    }
+}
+
+impl Error for RegistryError {}
+
+impl From<RegistryError> for io::Error {
+    fn from(error: RegistryError) -> Self {
+        let kind = match error {
+            RegistryError::Duplicate(..) => ErrorKind::AlreadyExists,
+            RegistryError::Unrecognised(_) => ErrorKind::InvalidData,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+/// Declares the module of the puzzle and adds it to the `puzzles!` table of `source`, keeping both
+/// sorted by year and day.
+fn register_puzzle(source: &str, year: u16, day: u8, title: &str) -> Result<String, RegistryError> {
+    let entry = format!(
+        "    ({year}, {day}, {title:?}) => {}::{},",
+        module_name(year, day),
+        solver_name(year, day)
+    );
+    let declaration = format!("mod {};", module_name(year, day));
+    let mut lines: Vec<&str> = source.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| *line == "puzzles! {")
+        .ok_or_else(|| RegistryError::Unrecognised("expected a `puzzles! {` table".to_string()))?
+        + 1;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| *line == "}")
+            .ok_or_else(|| {
+                RegistryError::Unrecognised("expected the end of the `puzzles!` table".to_string())
+            })?;
+    let mut index = end;
+    for (i, line) in lines.iter().enumerate().take(end).skip(start) {
+        let key = line
+            .trim_start()
+            .strip_prefix('(')
+            .and_then(|line| line.split_once(", \""))
+            .and_then(|(key, _)| key.split_once(", "))
+            .and_then(|(y, d)| Some((y.parse::<u16>().ok()?, d.parse::<u8>().ok()?)))
+            .ok_or_else(|| {
+                RegistryError::Unrecognised(format!("unexpected line `{}`", line.trim()))
+            })?;
+        if key == (year, day) {
+            return Err(RegistryError::Duplicate(year, day));
+        }
+        if key > (year, day) {
+            index = i;
+            break;
+        }
+    }
+    lines.insert(index, &entry);
+    // The declarations come before the table, so inserting one doesn't move the entry above.
+    let modules: Vec<usize> = (0..start)
+        .filter(|&i| lines[i].starts_with("mod solver_"))
+        .collect();
+    let last = modules.last().ok_or_else(|| {
+        RegistryError::Unrecognised("expected `mod solver_YYYY_DD;` declarations".to_string())
+    })?;
+    if modules.iter().any(|&i| lines[i] == declaration) {
+        return Err(RegistryError::Duplicate(year, day));
+    }
+    let index = modules
+        .iter()
+        .copied()
+        .find(|&i| lines[i] > declaration.as_str())
+        .unwrap_or(last + 1);
+    lines.insert(index, &declaration);
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Finds the crate to scaffold in: the closest of `start` and its parents with a `Cargo.toml`.
+pub fn find_root(start: &Path) -> Option<&Path> {
+    start
+        .ancestors()
+        .find(|dir| dir.join("Cargo.toml").is_file())
+}
+
+/// Creates the module of a new solver under `root` and registers it, returning the touched files.
+///
+/// The registry is only edited once every file of the module is written, so that a failure never
+/// leaves a puzzle registered without its module.
+pub fn create(
+    root: &Path,
+    year: u16,
+    day: u8,
+    title: &str,
+    grammar: bool,
+) -> io::Result<Vec<PathBuf>> {
+    let dir = root.join("src/solver").join(module_name(year, day));
+    if dir.exists() {
+        return Err(io::Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", dir.display()),
+        ));
+    }
+    let registry = root.join("src/solver/mod.rs");
+    let updated = register_puzzle(&fs::read_to_string(&registry)?, year, day, title)?;
+    fs::create_dir(&dir)?;
+    let mut files = Vec::new();
+    let mut write = |name: &str, contents: &str| {
+        fs::write(dir.join(name), contents).map(|_| files.push(dir.join(name)))
+    };
+    let written = write("mod.rs", &solver_module(year, day, grammar))
+        .and_then(|_| write("example.txt", ""))
+        .and_then(|_| write("input.txt", ""))
+        .and_then(|_| {
+            if grammar {
+                write("grammar.pest", GRAMMAR)
+            } else {
+                Ok(())
+            }
+        })
+        .and_then(|_| fs::write(&registry, updated));
+    if let Err(error) = written {
+        // A half-written module would make the next attempt fail on the existing directory.
+        let _ = fs::remove_dir_all(&dir);
+        return Err(error);
+    }
+    files.push(registry);
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+mod solver;
+mod solver_2015_01;
+mod solver_2022_01;
+
+puzzles! {
+    (2015, 1, \"Not Quite Lisp\") => solver_2015_01::Solver2015_01,
+    (2022, 1, \"Calorie Counting\") => solver_2022_01::Solver2022_01,
+}
+";
+
+    #[test]
+    fn should_register_puzzle_in_order() {
+        assert_eq!(
+            register_puzzle(REGISTRY, 2016, 3, "Squares With \"Three\" Sides").unwrap(),
+            "\
+mod solver;
+mod solver_2015_01;
+mod solver_2016_03;
+mod solver_2022_01;
+
+puzzles! {
+    (2015, 1, \"Not Quite Lisp\") => solver_2015_01::Solver2015_01,
+    (2016, 3, \"Squares With \\\"Three\\\" Sides\") => solver_2016_03::Solver2016_03,
+    (2022, 1, \"Calorie Counting\") => solver_2022_01::Solver2022_01,
+}
+"
+        );
+        assert_eq!(
+            register_puzzle(REGISTRY, 2022, 1, "Calorie Counting"),
+            Err(RegistryError::Duplicate(2022, 1))
+        );
+    }
+
+    #[test]
+    fn should_reject_unrecognised_registry() {
+        assert_eq!(
+            register_puzzle("puzzles! {\n    garbage\n}\n", 2015, 2, "I Was Told"),
+            Err(RegistryError::Unrecognised(
+                "unexpected line `garbage`".to_string()
+            ))
+        );
+        assert!(matches!(
+            register_puzzle("", 2015, 2, "I Was Told"),
+            Err(RegistryError::Unrecognised(_))
+        ));
+        assert_eq!(
+            register_puzzle("puzzles! {\n}\n", 2015, 2, "I Was Told"),
+            Err(RegistryError::Unrecognised(
+                "expected `mod solver_YYYY_DD;` declarations".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn should_not_create_module_for_duplicate_puzzle() {
+        let root = std::env::temp_dir().join(format!("scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/solver")).unwrap();
+        fs::write(root.join("src/solver/mod.rs"), REGISTRY).unwrap();
+        let error = create(&root, 2022, 1, "Calorie Counting", false).unwrap_err();
+        let created = root.join("src/solver/solver_2022_01").exists();
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(error.kind(), ErrorKind::AlreadyExists);
+        assert!(!created);
+    }
+
+    #[test]
+    fn should_find_root_in_parents() {
+        let root = std::env::temp_dir().join(format!("scaffold-root-{}", std::process::id()));
+        let nested = root.join("src/solver");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join("Cargo.toml"), "").unwrap();
+        let found = find_root(&nested).map(Path::to_path_buf);
+        fs::remove_dir_all(&root).unwrap();
+        assert_eq!(found, Some(root));
+    }
+}
@@ -0,0 +1,169 @@
+use super::ocr;
+use serde_json::{json, Value};
+use std::fmt::{Display, Formatter};
+
+// Integers beyond this lose precision as JavaScript numbers.
+const MAX_SAFE_INTEGER: i128 = (1 << 53) - 1;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    /// The rows of a screen, with the letters they spell when they are drawn in the puzzle font.
+    Grid {
+        rows: Vec<String>,
+        letters: Option<String>,
+    },
+    List(Vec<Answer>),
+}
+
+/// Rows of a screen drawn with `#` and `.`, such as the CRT of 2022 day 10.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid(pub Vec<String>);
+
+impl Answer {
+    fn integer(value: i128) -> Self {
+        if value.unsigned_abs() <= MAX_SAFE_INTEGER as u128 {
+            Answer::Integer(value as i64)
+        } else {
+            Answer::BigInteger(value)
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Answer::Integer(_) => "integer",
+            Answer::BigInteger(_) => "big_integer",
+            Answer::Text(_) => "text",
+            Answer::Grid { .. } => "grid",
+            Answer::List(_) => "list",
+        }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let value = match self {
+            Answer::Integer(value) => json!(value),
+            Answer::BigInteger(value) => json!(value.to_string()),
+            Answer::Text(text) => json!(text),
+            Answer::Grid { rows, letters } => {
+                return json!({ "type": self.kind(), "value": letters, "grid": rows });
+            }
+            Answer::List(items) => Value::Array(items.iter().map(Answer::to_json).collect()),
+        };
+        json!({ "type": self.kind(), "value": value })
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::BigInteger(value) => write!(f, "{value}"),
+            Answer::Text(text) => write!(f, "{text}"),
+            Answer::Grid {
+                letters: Some(letters),
+                ..
+            } => write!(f, "{letters}"),
+            Answer::Grid { rows, .. } => rows.iter().try_for_each(|row| writeln!(f, "{row}")),
+            Answer::List(items) => {
+                let items: Vec<String> = items.iter().map(Answer::to_string).collect();
+                write!(f, "{}", items.join(","))
+            }
+        }
+    }
+}
+
+impl Display for Grid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Answer::from(self.clone()).fmt(f)
+    }
+}
+
+macro_rules! integer_answers {
+    ($($integer:ty),*) => {
+        $(impl From<$integer> for Answer {
+            fn from(value: $integer) -> Self {
+                Answer::integer(value as i128)
+            }
+        })*
+    };
+}
+
+integer_answers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<Grid> for Answer {
+    fn from(grid: Grid) -> Self {
+        Answer::Grid {
+            letters: ocr::read(&grid.0),
+            rows: grid.0,
+        }
+    }
+}
+
+impl<T: Into<Answer>> From<Vec<T>> for Answer {
+    fn from(items: Vec<T>) -> Self {
+        Answer::List(items.into_iter().map(Into::into).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_large_integers_exact() {
+        assert_eq!(
+            Answer::from(1514285714288usize),
+            Answer::Integer(1514285714288)
+        );
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::BigInteger(18446744073709551615)
+        );
+        assert_eq!(
+            Answer::from(u64::MAX).to_json(),
+            json!({ "type": "big_integer", "value": "18446744073709551615" })
+        );
+        assert_eq!(Answer::from(i128::MIN), Answer::BigInteger(i128::MIN));
+        assert_eq!(
+            Answer::from(-9007199254740991i64),
+            Answer::Integer(-9007199254740991)
+        );
+    }
+
+    #[test]
+    fn should_format_answers() {
+        assert_eq!(Answer::from(vec![4, 6, 3]).to_string(), "4,6,3");
+        assert_eq!(
+            Answer::from(Grid(vec!["#.".to_string(), ".#".to_string()])).to_string(),
+            "#.\n.#\n"
+        );
+        assert_eq!(
+            Answer::from(vec![1, 2]).to_json(),
+            json!({
+                "type": "list",
+                "value": [{ "type": "integer", "value": 1 }, { "type": "integer", "value": 2 }]
+            })
+        );
+    }
+
+    #[test]
+    fn should_read_letters_of_grids() {
+        let rows: Vec<String> = ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]
+            .map(String::from)
+            .to_vec();
+        let answer = Answer::from(Grid(rows.clone()));
+        assert_eq!(answer.to_string(), "H");
+        assert_eq!(
+            answer.to_json(),
+            json!({ "type": "grid", "value": "H", "grid": rows })
+        );
+    }
+}
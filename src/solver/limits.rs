@@ -0,0 +1,326 @@
+use super::{ProgressHook, ProgressUpdate, SolveError};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicIsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// How often the runner looks at the clock and the heap while a part is solved.
+const POLL: Duration = Duration::from_millis(10);
+// Some solvers recurse deeper than the default stack of a thread allows.
+const STACK_SIZE: usize = 64 << 20;
+
+/// Runs `run` on a thread with the stack of a solving thread, for tests that solve puzzles directly.
+#[cfg(test)]
+pub fn with_solver_stack<T: Send + 'static>(run: impl FnOnce() -> T + Send + 'static) -> T {
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(run)
+        .expect("Could not spawn the solver thread")
+        .join()
+        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+}
+
+thread_local! {
+    // The heap counter of the part solved on this thread, which other threads don't touch. A raw
+    // pointer keeps the thread local free of destructors, as the allocator can't allocate for one.
+    static HEAP: Cell<*const Heap> = const { Cell::new(ptr::null()) };
+}
+
+/// The bytes a solving thread holds on the heap, and the most it held at once.
+#[derive(Default)]
+struct Heap {
+    size: AtomicIsize,
+    peak: AtomicIsize,
+}
+
+impl Heap {
+    // The worker may free more than it allocated, such as the input it was given.
+    fn peak(&self) -> usize {
+        self.peak.load(Ordering::Relaxed).max(0) as usize
+    }
+}
+
+fn count(bytes: isize) {
+    let _ = HEAP.try_with(|heap| {
+        // SAFETY: `Counting` clears the pointer before it drops the counter.
+        if let Some(heap) = unsafe { heap.get().as_ref() } {
+            let size = heap.size.fetch_add(bytes, Ordering::Relaxed) + bytes;
+            heap.peak.fetch_max(size, Ordering::Relaxed);
+        }
+    });
+}
+
+/// Keeps count of the bytes each solving thread holds on the heap, so that the runner can enforce
+/// a memory budget whatever the other threads allocate.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            count(layout.size() as isize);
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            count(layout.size() as isize);
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        count(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let reallocated = System.realloc(pointer, layout, new_size);
+        if !reallocated.is_null() {
+            count(new_size as isize - layout.size() as isize);
+        }
+        reallocated
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Counts the heap of the current thread into a counter until it is dropped.
+struct Counting(Arc<Heap>);
+
+impl Counting {
+    fn new(heap: Arc<Heap>) -> Self {
+        HEAP.with(|current| current.set(Arc::as_ptr(&heap)));
+        Self(heap)
+    }
+}
+
+impl Drop for Counting {
+    fn drop(&mut self) {
+        let _ = HEAP.try_with(|current| {
+            if current.get() == Arc::as_ptr(&self.0) {
+                current.set(ptr::null());
+            }
+        });
+    }
+}
+
+enum Message<T> {
+    Progress(ProgressUpdate),
+    Done(Result<T, SolveError>),
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    /// Bytes the heap may grow by while a part is solved.
+    pub memory: Option<usize>,
+    /// Cancels the part once set, even when its solver never reports its progress.
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl Limits {
+    /// Runs `solve` on a worker thread and forwards its progress to `report`, which may stop it.
+    /// Only the heap of the worker counts towards the memory budget, and by its peak, so that the
+    /// verdict doesn't depend on when it is checked.
+    ///
+    /// A worker that runs out of time or memory is asked to stop and left behind. Threads can't be
+    /// killed, so a solver that never reports its progress is leaked: it keeps its thread, a CPU
+    /// core and its heap until it finishes on its own or the process exits.
+    pub fn run<T: Send + 'static>(
+        &self,
+        solve: impl FnOnce(&ProgressHook) -> Result<T, SolveError> + Send + 'static,
+        report: &dyn Fn(ProgressUpdate) -> bool,
+    ) -> Result<T, SolveError> {
+        let (sender, receiver) = mpsc::channel();
+        let stop = Arc::new(AtomicBool::new(false));
+        let stopped = stop.clone();
+        let heap = Arc::new(Heap::default());
+        let counted = heap.clone();
+        let start = Instant::now();
+        let worker = thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(move || {
+                let _counting = Counting::new(counted);
+                let progress = sender.clone();
+                let forward = move |update| {
+                    let _ = progress.send(Message::Progress(update));
+                    !stopped.load(Ordering::Relaxed)
+                };
+                let _ = sender.send(Message::Done(solve(&ProgressHook::new(&forward))));
+            })
+            .expect("Could not spawn the solver thread");
+        let give_up = |error| {
+            stop.store(true, Ordering::Relaxed);
+            Err(error)
+        };
+        let over_budget = || self.memory.filter(|memory| heap.peak() > *memory);
+        loop {
+            match receiver.recv_timeout(POLL) {
+                Ok(Message::Progress(update)) => {
+                    if !report(update) {
+                        stop.store(true, Ordering::Relaxed);
+                    }
+                }
+                Ok(Message::Done(result)) => {
+                    return match over_budget() {
+                        Some(memory) => Err(SolveError::OutOfMemory(memory)),
+                        None => result,
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    let payload = worker
+                        .join()
+                        .expect_err("the solver thread always sends its result");
+                    // The caller may be a thread of the node runtime, which can't unwind.
+                    return Err(SolveError::Panicked(
+                        payload
+                            .downcast_ref::<&str>()
+                            .map(|message| message.to_string())
+                            .or_else(|| payload.downcast_ref::<String>().cloned())
+                            .unwrap_or_else(|| "unknown cause".to_string()),
+                    ));
+                }
+            }
+            if self
+                .cancel
+                .as_ref()
+                .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+            {
+                return give_up(SolveError::Cancelled);
+            }
+            if let Some(timeout) = self.timeout.filter(|timeout| start.elapsed() > *timeout) {
+                return give_up(SolveError::TimedOut(timeout));
+            }
+            if let Some(memory) = over_budget() {
+                return give_up(SolveError::OutOfMemory(memory));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wait_for_stop(progress: &ProgressHook) -> Result<usize, SolveError> {
+        loop {
+            progress.report(ProgressUpdate::default)?;
+            thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[test]
+    fn should_return_answer_within_limits() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(10)),
+            memory: None,
+            cancel: None,
+        };
+        assert_eq!(limits.run(|_| Ok(42), &|_| true), Ok(42));
+    }
+
+    #[test]
+    fn should_report_panic() {
+        assert_eq!(
+            Limits::default().run(
+                |_| -> Result<usize, _> { panic!("no wire {}", "a") },
+                &|_| true
+            ),
+            Err(SolveError::Panicked("no wire a".to_string()))
+        );
+    }
+
+    #[test]
+    fn should_time_out() {
+        let limits = Limits {
+            timeout: Some(Duration::from_millis(50)),
+            memory: None,
+            cancel: None,
+        };
+        assert_eq!(
+            limits.run(wait_for_stop, &|_| true),
+            Err(SolveError::TimedOut(Duration::from_millis(50)))
+        );
+    }
+
+    #[test]
+    fn should_enforce_memory_budget() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(10)),
+            memory: Some(1 << 20),
+            cancel: None,
+        };
+        let result = limits.run(
+            |progress| {
+                let heap = vec![1u8; 64 << 20];
+                wait_for_stop(progress).map(|_| heap.len())
+            },
+            &|_| true,
+        );
+        assert_eq!(result, Err(SolveError::OutOfMemory(1 << 20)));
+    }
+
+    #[test]
+    fn should_only_count_the_heap_of_the_worker() {
+        let limits = Limits {
+            timeout: Some(Duration::from_secs(10)),
+            memory: Some(1 << 20),
+            cancel: None,
+        };
+        let (allocate, allocating) = mpsc::channel();
+        let (allocated, wait_for_allocation) = mpsc::channel();
+        let other = thread::spawn(move || {
+            allocating.recv().unwrap();
+            let heap = vec![1u8; 64 << 20];
+            allocated.send(()).unwrap();
+            thread::sleep(POLL * 5);
+            heap.len()
+        });
+        let result = limits.run(
+            move |_| {
+                allocate.send(()).unwrap();
+                wait_for_allocation.recv().unwrap();
+                thread::sleep(POLL * 5);
+                Ok(42)
+            },
+            &|_| true,
+        );
+        assert_eq!(other.join().unwrap(), 64 << 20);
+        assert_eq!(result, Ok(42));
+    }
+
+    #[test]
+    fn should_cancel_solver_without_progress() {
+        let cancel = Arc::new(AtomicBool::new(false));
+        let limits = Limits {
+            cancel: Some(cancel.clone()),
+            ..Limits::default()
+        };
+        let result = limits.run(
+            move |_| {
+                cancel.store(true, Ordering::Relaxed);
+                thread::sleep(Duration::from_secs(10));
+                Ok(42)
+            },
+            &|_| true,
+        );
+        assert_eq!(result, Err(SolveError::Cancelled));
+    }
+
+    #[test]
+    fn should_stop_when_report_declines() {
+        assert_eq!(
+            Limits::default().run(wait_for_stop, &|_| false),
+            Err(SolveError::Cancelled)
+        );
+    }
+}
@@ -1,131 +1,270 @@
+mod answer;
+mod limits;
+mod ocr;
+pub mod parse;
+mod progress;
+mod question;
+mod registry;
 mod solver;
-pub use solver::Solver;
-
 mod solver_2015_01;
-pub use solver_2015_01::Solver2015_01;
 mod solver_2015_02;
-pub use solver_2015_02::Solver2015_02;
 mod solver_2015_03;
-pub use solver_2015_03::Solver2015_03;
 mod solver_2015_04;
-pub use solver_2015_04::Solver2015_04;
 mod solver_2015_05;
-pub use solver_2015_05::Solver2015_05;
 mod solver_2015_06;
-pub use solver_2015_06::Solver2015_06;
 mod solver_2015_07;
-pub use solver_2015_07::Solver2015_07;
 mod solver_2015_08;
-pub use solver_2015_08::Solver2015_08;
 mod solver_2015_09;
-pub use solver_2015_09::Solver2015_09;
 mod solver_2015_10;
-pub use solver_2015_10::Solver2015_10;
+mod solver_2015_11;
 mod solver_2015_12;
-pub use solver_2015_12::Solver2015_12;
 mod solver_2015_13;
-pub use solver_2015_13::Solver2015_13;
 mod solver_2015_14;
-pub use solver_2015_14::Solver2015_14;
 mod solver_2015_15;
-pub use solver_2015_15::Solver2015_15;
 mod solver_2015_16;
-pub use solver_2015_16::Solver2015_16;
 mod solver_2015_17;
-pub use solver_2015_17::Solver2015_17;
 mod solver_2015_18;
-pub use solver_2015_18::Solver2015_18;
 mod solver_2015_19;
-pub use solver_2015_19::Solver2015_19;
 mod solver_2022_01;
-pub use solver_2022_01::Solver2022_01;
 mod solver_2022_02;
-pub use solver_2022_02::Solver2022_02;
 mod solver_2022_03;
-pub use solver_2022_03::Solver2022_03;
 mod solver_2022_04;
-pub use solver_2022_04::Solver2022_04;
 mod solver_2022_05;
-pub use solver_2022_05::Solver2022_05;
 mod solver_2022_06;
-pub use solver_2022_06::Solver2022_06;
 mod solver_2022_07;
-pub use solver_2022_07::Solver2022_07;
 mod solver_2022_08;
-pub use solver_2022_08::Solver2022_08;
 mod solver_2022_09;
-pub use solver_2022_09::Solver2022_09;
 mod solver_2022_10;
-pub use solver_2022_10::Solver2022_10;
 mod solver_2022_11;
-pub use solver_2022_11::Solver2022_11;
 mod solver_2022_12;
-pub use solver_2022_12::Solver2022_12;
 mod solver_2022_13;
-pub use solver_2022_13::Solver2022_13;
 mod solver_2022_14;
-pub use solver_2022_14::Solver2022_14;
 mod solver_2022_15;
-pub use solver_2022_15::Solver2022_15;
 mod solver_2022_16;
-pub use solver_2022_16::Solver2022_16;
 mod solver_2022_17;
-pub use solver_2022_17::Solver2022_17;
 mod solver_2022_18;
-pub use solver_2022_18::Solver2022_18;
+mod solver_2022_19;
 mod solver_2022_20;
-pub use solver_2022_20::Solver2022_20;
 mod solver_2022_21;
-pub use solver_2022_21::Solver2022_21;
 mod solver_2022_22;
-pub use solver_2022_22::Solver2022_22;
+mod solver_2022_23;
+mod solver_2022_24;
+mod solver_2022_25;
 mod solver_2024_01;
-pub use solver_2024_01::Solver2024_01;
 mod solver_2024_02;
-pub use solver_2024_02::Solver2024_02;
 mod solver_2024_03;
-pub use solver_2024_03::Solver2024_03;
 mod solver_2024_04;
-pub use solver_2024_04::Solver2024_04;
 mod solver_2024_05;
-pub use solver_2024_05::Solver2024_05;
 mod solver_2024_06;
-pub use solver_2024_06::Solver2024_06;
 mod solver_2024_07;
-pub use solver_2024_07::Solver2024_07;
 mod solver_2024_08;
-pub use solver_2024_08::Solver2024_08;
 mod solver_2024_09;
-pub use solver_2024_09::Solver2024_09;
 mod solver_2024_10;
-pub use solver_2024_10::Solver2024_10;
 mod solver_2024_11;
-pub use solver_2024_11::Solver2024_11;
 mod solver_2024_12;
-pub use solver_2024_12::Solver2024_12;
 mod solver_2024_13;
-pub use solver_2024_13::Solver2024_13;
 mod solver_2024_14;
-pub use solver_2024_14::Solver2024_14;
 mod solver_2024_15;
-pub use solver_2024_15::Solver2024_15;
 mod solver_2024_16;
-pub use solver_2024_16::Solver2024_16;
 mod solver_2024_17;
-pub use solver_2024_17::Solver2024_17;
 mod solver_2024_18;
-pub use solver_2024_18::Solver2024_18;
 mod solver_2024_19;
-pub use solver_2024_19::Solver2024_19;
 mod solver_2024_20;
-pub use solver_2024_20::Solver2024_20;
 mod solver_2024_21;
-pub use solver_2024_21::Solver2024_21;
 mod solver_2024_22;
-pub use solver_2024_22::Solver2024_22;
 mod solver_2024_23;
-pub use solver_2024_23::Solver2024_23;
 mod solver_2024_24;
-pub use solver_2024_24::Solver2024_24;
 mod solver_2024_25;
-pub use solver_2024_25::Solver2024_25;
+pub use answer::{Answer, Grid};
+#[cfg(test)]
+pub use limits::with_solver_stack;
+pub use limits::Limits;
+pub use parse::ParseError;
+pub use progress::{ProgressHook, ProgressUpdate};
+pub use question::check as check_questions;
+pub use question::find_question;
+pub use registry::Puzzle;
+pub use solver::{SolveError, Solver};
+#[allow(unused_imports)] // Only the node bindings convert SNAFU numbers for now.
+pub use solver_2022_25::Snafu;
+
+macro_rules! puzzles {
+    ($(($year:literal, $day:literal, $title:literal) => $module:ident::$solver:ident,)*) => {
+        static PUZZLES: &[Puzzle] = &[
+            $(Puzzle {
+                year: $year,
+                day: $day,
+                title: $title,
+                answer_types: registry::answer_types::<$module::$solver, _, _>,
+                parse: |input| $module::$solver::try_from(input).map(registry::erase),
+                embedded: || registry::erase($module::$solver::default()),
+            },)*
+        ];
+    };
+}
+
+puzzles! {
+    (2015, 1, "Not Quite Lisp") => solver_2015_01::Solver2015_01,
+    (2015, 2, "I Was Told There Would Be No Math") => solver_2015_02::Solver2015_02,
+    (2015, 3, "Perfectly Spherical Houses in a Vacuum") => solver_2015_03::Solver2015_03,
+    (2015, 4, "The Ideal Stocking Stuffer") => solver_2015_04::Solver2015_04,
+    (2015, 5, "Doesn't He Have Intern-Elves For This?") => solver_2015_05::Solver2015_05,
+    (2015, 6, "Probably a Fire Hazard") => solver_2015_06::Solver2015_06,
+    (2015, 7, "Some Assembly Required") => solver_2015_07::Solver2015_07,
+    (2015, 8, "Matchsticks") => solver_2015_08::Solver2015_08,
+    (2015, 9, "All in a Single Night") => solver_2015_09::Solver2015_09,
+    (2015, 10, "Elves Look, Elves Say") => solver_2015_10::Solver2015_10,
+    (2015, 11, "Corporate Policy") => solver_2015_11::Solver2015_11,
+    (2015, 12, "JSAbacusFramework.io") => solver_2015_12::Solver2015_12,
+    (2015, 13, "Knights of the Dinner Table") => solver_2015_13::Solver2015_13,
+    (2015, 14, "Reindeer Olympics") => solver_2015_14::Solver2015_14,
+    (2015, 15, "Science for Hungry People") => solver_2015_15::Solver2015_15,
+    (2015, 16, "Aunt Sue") => solver_2015_16::Solver2015_16,
+    (2015, 17, "No Such Thing as Too Much") => solver_2015_17::Solver2015_17,
+    (2015, 18, "Like a GIF For Your Yard") => solver_2015_18::Solver2015_18,
+    (2015, 19, "Medicine for Rudolph") => solver_2015_19::Solver2015_19,
+    (2022, 1, "Calorie Counting") => solver_2022_01::Solver2022_01,
+    (2022, 2, "Rock Paper Scissors") => solver_2022_02::Solver2022_02,
+    (2022, 3, "Rucksack Reorganization") => solver_2022_03::Solver2022_03,
+    (2022, 4, "Camp Cleanup") => solver_2022_04::Solver2022_04,
+    (2022, 5, "Supply Stacks") => solver_2022_05::Solver2022_05,
+    (2022, 6, "Tuning Trouble") => solver_2022_06::Solver2022_06,
+    (2022, 7, "No Space Left On Device") => solver_2022_07::Solver2022_07,
+    (2022, 8, "Treetop Tree House") => solver_2022_08::Solver2022_08,
+    (2022, 9, "Rope Bridge") => solver_2022_09::Solver2022_09,
+    (2022, 10, "Cathode-Ray Tube") => solver_2022_10::Solver2022_10,
+    (2022, 11, "Monkey in the Middle") => solver_2022_11::Solver2022_11,
+    (2022, 12, "Hill Climbing Algorithm") => solver_2022_12::Solver2022_12,
+    (2022, 13, "Distress Signal") => solver_2022_13::Solver2022_13,
+    (2022, 14, "Regolith Reservoir") => solver_2022_14::Solver2022_14,
+    (2022, 15, "Beacon Exclusion Zone") => solver_2022_15::Solver2022_15,
+    (2022, 16, "Proboscidea Volcanium") => solver_2022_16::Solver2022_16,
+    (2022, 17, "Pyroclastic Flow") => solver_2022_17::Solver2022_17,
+    (2022, 18, "Boiling Boulders") => solver_2022_18::Solver2022_18,
+    (2022, 19, "Not Enough Minerals") => solver_2022_19::Solver2022_19,
+    (2022, 20, "Grove Positioning System") => solver_2022_20::Solver2022_20,
+    (2022, 21, "Monkey Math") => solver_2022_21::Solver2022_21,
+    (2022, 22, "Monkey Map") => solver_2022_22::Solver2022_22,
+    (2022, 23, "Unstable Diffusion") => solver_2022_23::Solver2022_23,
+    (2022, 24, "Blizzard Basin") => solver_2022_24::Solver2022_24,
+    (2022, 25, "Full of Hot Air") => solver_2022_25::Solver2022_25,
+    (2024, 1, "Historian Hysteria") => solver_2024_01::Solver2024_01,
+    (2024, 2, "Red-Nosed Reports") => solver_2024_02::Solver2024_02,
+    (2024, 3, "Mull It Over") => solver_2024_03::Solver2024_03,
+    (2024, 4, "Ceres Search") => solver_2024_04::Solver2024_04,
+    (2024, 5, "Print Queue") => solver_2024_05::Solver2024_05,
+    (2024, 6, "Guard Gallivant") => solver_2024_06::Solver2024_06,
+    (2024, 7, "Bridge Repair") => solver_2024_07::Solver2024_07,
+    (2024, 8, "Resonant Collinearity") => solver_2024_08::Solver2024_08,
+    (2024, 9, "Disk Fragmenter") => solver_2024_09::Solver2024_09,
+    (2024, 10, "Hoof It") => solver_2024_10::Solver2024_10,
+    (2024, 11, "Plutonian Pebbles") => solver_2024_11::Solver2024_11,
+    (2024, 12, "Garden Groups") => solver_2024_12::Solver2024_12,
+    (2024, 13, "Claw Contraption") => solver_2024_13::Solver2024_13,
+    (2024, 14, "Restroom Redoubt") => solver_2024_14::Solver2024_14,
+    (2024, 15, "Warehouse Woes") => solver_2024_15::Solver2024_15,
+    (2024, 16, "Reindeer Maze") => solver_2024_16::Solver2024_16,
+    (2024, 17, "Chronospatial Computer") => solver_2024_17::Solver2024_17,
+    (2024, 18, "RAM Run") => solver_2024_18::Solver2024_18,
+    (2024, 19, "Linen Layout") => solver_2024_19::Solver2024_19,
+    (2024, 20, "Race Condition") => solver_2024_20::Solver2024_20,
+    (2024, 21, "Keypad Conundrum") => solver_2024_21::Solver2024_21,
+    (2024, 22, "Monkey Market") => solver_2024_22::Solver2024_22,
+    (2024, 23, "LAN Party") => solver_2024_23::Solver2024_23,
+    (2024, 24, "Crossed Wires") => solver_2024_24::Solver2024_24,
+    (2024, 25, "Code Chronicle") => solver_2024_25::Solver2024_25,
+}
+
+pub fn puzzles() -> &'static [Puzzle] {
+    PUZZLES
+}
+
+pub fn find_puzzle(year: u16, day: u8) -> Option<&'static Puzzle> {
+    PUZZLES
+        .iter()
+        .find(|puzzle| puzzle.year == year && puzzle.day == day)
+}
+
+pub fn years() -> Vec<u16> {
+    let mut years: Vec<u16> = PUZZLES.iter().map(|puzzle| puzzle.year).collect();
+    years.dedup();
+    years
+}
+
+pub fn list<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Explains which puzzles are solved, for when `year`/`day` is not.
+pub fn unsolved(year: u16, day: u8) -> String {
+    let days: Vec<u8> = PUZZLES
+        .iter()
+        .filter(|puzzle| puzzle.year == year)
+        .map(|puzzle| puzzle.day)
+        .collect();
+    if days.is_empty() {
+        format!(
+            "{year} Day {day} is not solved yet, nor any other puzzle of {year}. Solved years: {}",
+            list(&years())
+        )
+    } else {
+        format!(
+            "{year} Day {day} is not solved yet. Solved days of {year}: {}",
+            list(&days)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_register_each_puzzle_once() {
+        for (i, puzzle) in puzzles().iter().enumerate() {
+            assert!(puzzles()[..i]
+                .iter()
+                .all(|other| (other.year, other.day) != (puzzle.year, puzzle.day)));
+        }
+    }
+
+    #[test]
+    fn should_find_registered_puzzle() {
+        let puzzle = find_puzzle(2022, 10).unwrap();
+        assert_eq!(puzzle.title, "Cathode-Ray Tube");
+        assert_eq!(puzzle.answer_types(), ["i32", "Grid"]);
+        assert!(find_puzzle(2015, 20).is_none());
+    }
+
+    #[test]
+    fn should_explain_unsolved_puzzle() {
+        assert!(unsolved(2015, 20)
+            .starts_with("2015 Day 20 is not solved yet. Solved days of 2015: 1, 2, 3,"));
+        assert_eq!(
+            unsolved(2014, 1),
+            format!(
+                "2014 Day 1 is not solved yet, nor any other puzzle of 2014. Solved years: {}",
+                list(&years())
+            )
+        );
+    }
+
+    #[test]
+    fn should_parse_given_input() {
+        let puzzle = find_puzzle(2015, 1).unwrap();
+        let solver = puzzle.solver(Some("(()))\n")).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), Answer::Integer(-1));
+        assert_eq!(solver.solve_second_part().unwrap(), Answer::Integer(5));
+    }
+
+    #[test]
+    fn should_report_invalid_input() {
+        let puzzle = find_puzzle(2015, 1).unwrap();
+        let error = puzzle.solver(Some("(()x(\n")).err().unwrap();
+        assert_eq!(error, ParseError::new(1, 4, "unexpected `x`"));
+    }
+}
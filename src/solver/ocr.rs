@@ -0,0 +1,195 @@
+// The letters Advent of Code draws with `#` and `.`, in the small font that is 6 rows high and
+// the large one that is 10 rows high.
+const SMALL: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+];
+
+const LARGE: &[(char, &str)] = &[
+    (
+        'A',
+        "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'B',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+    ),
+    (
+        'C',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+    ),
+    (
+        'E',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'F',
+        "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'G',
+        ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+    ),
+    (
+        'H',
+        "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+    ),
+    (
+        'J',
+        "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+    ),
+    (
+        'K',
+        "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+    ),
+    (
+        'L',
+        "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+    ),
+    (
+        'N',
+        "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+    ),
+    (
+        'P',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+    ),
+    (
+        'R',
+        "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+    ),
+    (
+        'X',
+        "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+    ),
+    (
+        'Z',
+        "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+    ),
+];
+
+fn is_lit(pixel: u8) -> bool {
+    pixel == b'#'
+}
+
+/// Splits `rows` into the glyphs between blank columns, each as its columns.
+fn glyphs(rows: &[&[u8]]) -> Vec<Vec<Vec<bool>>> {
+    let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+    let column = |x: usize| -> Vec<bool> {
+        rows.iter()
+            .map(|row| row.get(x).copied().is_some_and(is_lit))
+            .collect()
+    };
+    let mut glyphs = vec![];
+    let mut glyph: Vec<Vec<bool>> = vec![];
+    for x in 0..width {
+        let column = column(x);
+        if column.iter().any(|lit| *lit) {
+            glyph.push(column);
+        } else if !glyph.is_empty() {
+            glyphs.push(std::mem::take(&mut glyph));
+        }
+    }
+    if !glyph.is_empty() {
+        glyphs.push(glyph);
+    }
+    glyphs
+}
+
+/// Reads the capital letters drawn in `rows`, if every glyph is a letter of the puzzle fonts.
+pub fn read(rows: &[String]) -> Option<String> {
+    let font = match rows.len() {
+        6 => SMALL,
+        10 => LARGE,
+        _ => return None,
+    };
+    let rows: Vec<&[u8]> = rows.iter().map(|row| row.as_bytes()).collect();
+    let letters: Vec<(char, Vec<Vec<bool>>)> = font
+        .iter()
+        .map(|(letter, glyph)| {
+            let glyph: Vec<&[u8]> = glyph.lines().map(str::as_bytes).collect();
+            (*letter, self::glyphs(&glyph).remove(0))
+        })
+        .collect();
+    let glyphs = glyphs(&rows);
+    if glyphs.is_empty() {
+        return None;
+    }
+    glyphs
+        .iter()
+        .map(|glyph| {
+            letters
+                .iter()
+                .find(|(_, columns)| columns == glyph)
+                .map(|(letter, _)| *letter)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(grid: &str) -> Vec<String> {
+        grid.lines().map(String::from).collect()
+    }
+
+    #[test]
+    fn should_read_small_letters() {
+        let grid = "\
+####..##...##....##.####...##.####.#....
+...#.#..#.#..#....#....#....#.#....#....
+..#..#....#.......#...#.....#.###..#....
+.#...#.##.#.......#..#......#.#....#....
+#....#..#.#..#.#..#.#....#..#.#....#....
+####..###..##...##..####..##..#....####.
+";
+        assert_eq!(read(&rows(grid)), Some("ZGCJZJFL".to_string()));
+    }
+
+    #[test]
+    fn should_read_large_letters() {
+        let grid = "\
+#....#..######
+#....#.......#
+.#..#........#
+.#..#.......#.
+..##.......#..
+..##......#...
+.#..#....#....
+.#..#...#.....
+#....#..#.....
+#....#..######
+";
+        assert_eq!(read(&rows(grid)), Some("XZ".to_string()));
+    }
+
+    #[test]
+    fn should_not_read_unknown_glyphs() {
+        let grid = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+        assert_eq!(read(&rows(grid)), None);
+    }
+}
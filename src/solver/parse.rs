@@ -0,0 +1,148 @@
+use pest::error::{Error as PestError, LineColLocation};
+use pest::iterators::Pair;
+use pest::RuleType;
+use regex::{Captures, Regex};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Reports an error at `span`, which has to be a slice of `input`.
+    pub fn at(input: &str, span: &str, message: impl Into<String>) -> Self {
+        let offset = (span.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self::new(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    pub fn unexpected(input: &str, span: &str) -> Self {
+        Self::at(input, span, format!("unexpected `{span}`"))
+    }
+
+    pub fn end_of_input(input: &str, expected: &str) -> Self {
+        Self::at(
+            input,
+            &input[input.len()..],
+            format!("expected {expected}, found end of input"),
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+impl<R: RuleType> From<PestError<R>> for ParseError {
+    fn from(error: PestError<R>) -> Self {
+        let (line, column) = match error.line_col {
+            LineColLocation::Pos(position) => position,
+            LineColLocation::Span(start, _) => start,
+        };
+        Self::new(line, column, error.variant.message())
+    }
+}
+
+/// Parses `span`, a slice of `input`, into any `FromStr` type.
+pub fn value<T>(input: &str, span: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    span.parse()
+        .map_err(|error| ParseError::at(input, span, format!("invalid value `{span}`: {error}")))
+}
+
+/// Parses the text matched by a pest `pair` into any `FromStr` type.
+pub fn pair_value<T, R>(pair: &Pair<R>) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+    R: RuleType,
+{
+    pair.as_str().parse().map_err(|error| {
+        let (line, column) = pair.as_span().start_pos().line_col();
+        ParseError::new(
+            line,
+            column,
+            format!("invalid value `{}`: {error}", pair.as_str()),
+        )
+    })
+}
+
+/// Fails at the first character of `span`, a slice of `input`, that is not one of `allowed`.
+pub fn allowed_chars(input: &str, span: &str, allowed: &str) -> Result<(), ParseError> {
+    match span.char_indices().find(|(_, c)| !allowed.contains(*c)) {
+        Some((i, c)) => Err(ParseError::unexpected(input, &span[i..i + c.len_utf8()])),
+        None => Ok(()),
+    }
+}
+
+/// Matches `re` against `haystack`, a slice of `input`.
+pub fn captures<'h>(
+    input: &str,
+    re: &Regex,
+    haystack: &'h str,
+) -> Result<Captures<'h>, ParseError> {
+    re.captures(haystack).ok_or_else(|| {
+        ParseError::at(
+            input,
+            haystack,
+            format!("expected a match for `{}`", re.as_str()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_locate_span() {
+        let input = "12\n3x4\n56";
+        let error = ParseError::at(input, &input[4..5], "unexpected `x`");
+        assert_eq!(error, ParseError::new(2, 2, "unexpected `x`"));
+    }
+
+    #[test]
+    fn should_locate_end_of_input() {
+        let error = ParseError::end_of_input("12\n34", "a number");
+        assert_eq!(error.line, 2);
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn should_parse_value() {
+        let input = "1,2,x";
+        assert_eq!(value::<u8>(input, &input[2..3]), Ok(2));
+        assert_eq!(
+            value::<u8>(input, &input[4..5]).unwrap_err().to_string(),
+            "1:5: invalid value `x`: invalid digit found in string"
+        );
+    }
+}
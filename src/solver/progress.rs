@@ -0,0 +1,89 @@
+use super::SolveError;
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+// Solvers may report on every state, the hook only sees a few updates per second.
+const INTERVAL: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProgressUpdate {
+    pub states: u64,
+    pub best: Option<i64>,
+    pub iteration: Option<u64>,
+}
+
+/// Receives the progress of a long-running solver, which stops once `report` returns false.
+pub struct ProgressHook<'a> {
+    report: Option<&'a dyn Fn(ProgressUpdate) -> bool>,
+    last: Cell<Option<Instant>>,
+}
+
+impl<'a> ProgressHook<'a> {
+    pub fn new(report: &'a dyn Fn(ProgressUpdate) -> bool) -> Self {
+        Self {
+            report: Some(report),
+            last: Cell::new(None),
+        }
+    }
+
+    pub fn none() -> Self {
+        Self {
+            report: None,
+            last: Cell::new(None),
+        }
+    }
+
+    pub fn report(&self, update: impl FnOnce() -> ProgressUpdate) -> Result<(), SolveError> {
+        let Some(report) = self.report else {
+            return Ok(());
+        };
+        let now = Instant::now();
+        if self
+            .last
+            .get()
+            .is_some_and(|last| now.duration_since(last) < INTERVAL)
+        {
+            return Ok(());
+        }
+        self.last.set(Some(now));
+        if report(update()) {
+            Ok(())
+        } else {
+            Err(SolveError::Cancelled)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_throttle_updates() {
+        let count = Cell::new(0);
+        let report = |_| {
+            count.set(count.get() + 1);
+            true
+        };
+        let hook = ProgressHook::new(&report);
+        for states in 0..1000 {
+            hook.report(|| ProgressUpdate {
+                states,
+                ..Default::default()
+            })
+            .unwrap();
+        }
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn should_cancel_when_the_hook_declines() {
+        let report = |_| false;
+        let hook = ProgressHook::new(&report);
+        assert_eq!(
+            hook.report(ProgressUpdate::default),
+            Err(SolveError::Cancelled)
+        );
+        assert_eq!(ProgressHook::none().report(ProgressUpdate::default), Ok(()));
+    }
+}
@@ -0,0 +1,365 @@
+use super::{find_puzzle, puzzles, Answer, ParseError, Puzzle};
+use regex::Regex;
+use std::fmt::{Display, Formatter};
+
+static QUESTIONS: &[(u16, u8, &str)] = include!(concat!(env!("OUT_DIR"), "/questions.rs"));
+
+#[derive(Debug, PartialEq)]
+pub struct Part {
+    pub description: String,
+    pub examples: Vec<String>,
+}
+
+/// The answer an example block should produce, from a `<!-- answer partN: ... -->` comment
+/// following the block.
+#[derive(Debug, PartialEq)]
+pub struct ExampleAnswer {
+    pub input: String,
+    pub part: u8,
+    pub answer: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Question {
+    pub year: u16,
+    pub day: u8,
+    pub title: String,
+    pub parts: Vec<Part>,
+    pub links: Vec<String>,
+    pub answers: Vec<ExampleAnswer>,
+}
+
+impl Question {
+    pub fn parse(year: u16, day: u8, markdown: &str) -> Result<Self, ParseError> {
+        let re_title = Regex::new(r"^# Day (\d+): (.+)$").unwrap();
+        let re_link = Regex::new(r"\]\(([^)\s]+)\)").unwrap();
+        let re_answer = Regex::new(r"^<!-- answer part([12]): (.+) -->$").unwrap();
+        let mut lines = markdown.lines();
+        let heading = lines
+            .next()
+            .ok_or_else(|| ParseError::end_of_input(markdown, "a `# Day N: Title` heading"))?;
+        let captures = re_title
+            .captures(heading)
+            .ok_or_else(|| ParseError::new(1, 1, "expected a `# Day N: Title` heading"))?;
+        if captures[1] != day.to_string() {
+            return Err(ParseError::new(
+                1,
+                7,
+                format!(
+                    "expected the question of day {day}, found day {}",
+                    &captures[1]
+                ),
+            ));
+        }
+        let title = captures[2].to_string();
+
+        let mut parts = vec![Part {
+            description: String::new(),
+            examples: vec![],
+        }];
+        let mut links = vec![];
+        let mut answers = vec![];
+        let mut example: Option<String> = None;
+        let mut last_example: Option<String> = None;
+        for (index, line) in lines.enumerate() {
+            let part = parts.last_mut().unwrap();
+            match &mut example {
+                Some(text) if line.starts_with("```") => {
+                    part.examples.push(text.clone());
+                    last_example = example.take();
+                }
+                Some(text) => {
+                    *text += line;
+                    *text += "\n";
+                }
+                None if line.starts_with("```") => example = Some(String::new()),
+                None if re_answer.is_match(line) => {
+                    let captures = re_answer.captures(line).unwrap();
+                    let input = last_example.clone().ok_or_else(|| {
+                        ParseError::new(index + 2, 1, "expected an example before the answer")
+                    })?;
+                    answers.push(ExampleAnswer {
+                        input,
+                        part: captures[1].parse().unwrap(),
+                        answer: captures[2].to_string(),
+                    });
+                    continue;
+                }
+                None if line.starts_with('#') && line.ends_with("Part Two") => {
+                    parts.push(Part {
+                        description: String::new(),
+                        examples: vec![],
+                    });
+                    continue;
+                }
+                None => {
+                    links.extend(
+                        re_link
+                            .captures_iter(line)
+                            .map(|captures| captures[1].to_string()),
+                    );
+                }
+            }
+            let part = parts.last_mut().unwrap();
+            part.description += line;
+            part.description += "\n";
+        }
+        if example.is_some() {
+            return Err(ParseError::end_of_input(markdown, "the end of the example"));
+        }
+        for part in &mut parts {
+            part.description = part.description.trim().to_string();
+        }
+        Ok(Self {
+            year,
+            day,
+            title,
+            parts,
+            links,
+            answers,
+        })
+    }
+}
+
+pub fn find_question(year: u16, day: u8) -> Option<Result<Question, ParseError>> {
+    QUESTIONS
+        .iter()
+        .find(|question| question.0 == year && question.1 == day)
+        .map(|&(year, day, markdown)| Question::parse(year, day, markdown))
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Issue {
+    MissingQuestion(u16, u8),
+    MissingSolver(u16, u8),
+    BrokenLink(u16, u8, String),
+    Invalid(u16, u8, ParseError),
+    WrongExample {
+        year: u16,
+        day: u8,
+        part: u8,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl Display for Issue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Issue::MissingQuestion(year, day) => {
+                write!(f, "{year} Day {day} has a solver but no question")
+            }
+            Issue::MissingSolver(year, day) => {
+                write!(f, "{year} Day {day} has a question but no solver")
+            }
+            Issue::BrokenLink(year, day, link) => {
+                write!(f, "{year} Day {day} links to the missing question `{link}`")
+            }
+            Issue::Invalid(year, day, error) => {
+                write!(f, "{year} Day {day} has an invalid question: {error}")
+            }
+            Issue::WrongExample {
+                year,
+                day,
+                part,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "{year} Day {day} part {part} should answer {expected:?} for its example, got {actual:?}"
+            ),
+        }
+    }
+}
+
+fn check_questions(questions: &[(u16, u8, &str)], solved: &[(u16, u8)]) -> Vec<Issue> {
+    let has_question = |year, day| questions.iter().any(|q| q.0 == year && q.1 == day);
+    let mut issues: Vec<Issue> = solved
+        .iter()
+        .filter(|(year, day)| !has_question(*year, *day))
+        .map(|&(year, day)| Issue::MissingQuestion(year, day))
+        .collect();
+    for &(year, day, markdown) in questions {
+        if !solved.contains(&(year, day)) {
+            issues.push(Issue::MissingSolver(year, day));
+        }
+        let question = match Question::parse(year, day, markdown) {
+            Ok(question) => question,
+            Err(error) => {
+                issues.push(Issue::Invalid(year, day, error));
+                continue;
+            }
+        };
+        for link in question.links {
+            let target = link.trim_start_matches("./");
+            if target.contains(':') || !target.ends_with(".md") {
+                continue;
+            }
+            let linked = target
+                .trim_end_matches(".md")
+                .split_once('_')
+                .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)));
+            if !linked.is_some_and(|(year, day)| has_question(year, day)) {
+                issues.push(Issue::BrokenLink(year, day, link));
+            }
+        }
+    }
+    issues
+}
+
+/// Runs the examples of `question` that have an answer through the solver of `puzzle`.
+fn check_examples(question: &Question, puzzle: &Puzzle) -> Vec<Issue> {
+    question
+        .answers
+        .iter()
+        .filter_map(|example| {
+            let actual = match puzzle.solver(Some(&example.input)) {
+                Ok(solver) if example.part == 1 => solver.solve_first_part(),
+                Ok(solver) => solver.solve_second_part(),
+                Err(error) => Ok(Answer::Text(format!("invalid input: {error}"))),
+            };
+            let actual = match actual {
+                Ok(actual) if actual.to_string() == example.answer => return None,
+                Ok(actual) => actual.to_string(),
+                Err(error) => format!("error: {error}"),
+            };
+            Some(Issue::WrongExample {
+                year: question.year,
+                day: question.day,
+                part: example.part,
+                expected: example.answer.clone(),
+                actual,
+            })
+        })
+        .collect()
+}
+
+fn check_all_examples() -> Vec<Issue> {
+    QUESTIONS
+        .iter()
+        .filter_map(|&(year, day, markdown)| {
+            Some((
+                Question::parse(year, day, markdown).ok()?,
+                find_puzzle(year, day)?,
+            ))
+        })
+        .flat_map(|(question, puzzle)| check_examples(&question, puzzle))
+        .collect()
+}
+
+/// Reports solvers without a question, questions without a solver, links to missing questions and
+/// examples the solvers get wrong.
+pub fn check() -> Vec<Issue> {
+    let solved: Vec<_> = puzzles()
+        .iter()
+        .map(|puzzle| (puzzle.year, puzzle.day))
+        .collect();
+    let mut issues = check_questions(QUESTIONS, &solved);
+    issues.extend(check_all_examples());
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::with_solver_stack;
+
+    #[test]
+    fn should_parse_question() {
+        let question = find_question(2024, 10).unwrap().unwrap();
+        assert_eq!(question.title, "Hoof It");
+        assert_eq!(question.parts.len(), 2);
+        assert_eq!(question.parts[0].examples.len(), 5);
+        assert_eq!(question.parts[0].examples[0], "0123\n1234\n8765\n9876\n");
+        assert!(question.parts[1]
+            .description
+            .starts_with("The reindeer spends a few minutes"));
+        assert!(question.links.contains(&"./2023_15.md".to_string()));
+    }
+
+    #[test]
+    fn should_parse_example_answers() {
+        let question = Question::parse(
+            2022,
+            1,
+            "# Day 1: A\n\n```\n1\n\n2\n```\n\n<!-- answer part1: 2 -->\n\n## Part Two\n\n<!-- answer part2: 3 -->\n",
+        )
+        .unwrap();
+        assert_eq!(
+            question.answers,
+            vec![
+                ExampleAnswer {
+                    input: "1\n\n2\n".to_string(),
+                    part: 1,
+                    answer: "2".to_string(),
+                },
+                ExampleAnswer {
+                    input: "1\n\n2\n".to_string(),
+                    part: 2,
+                    answer: "3".to_string(),
+                },
+            ]
+        );
+        assert_eq!(question.parts[0].description, "```\n1\n\n2\n```");
+    }
+
+    #[test]
+    fn should_reject_question_of_another_day() {
+        let error = Question::parse(2024, 8, "# Day 9: Disk Fragmenter")
+            .err()
+            .unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(1, 7, "expected the question of day 8, found day 9")
+        );
+    }
+
+    #[test]
+    fn should_solve_every_example() {
+        with_solver_stack(|| {
+            let issues: Vec<String> = check_all_examples()
+                .iter()
+                .map(|issue| issue.to_string())
+                .collect();
+            assert!(issues.is_empty(), "{}", issues.join("\n"));
+        });
+    }
+
+    #[test]
+    fn should_reject_question_without_title() {
+        let error = Question::parse(2015, 1, "Santa was hoping").err().unwrap();
+        assert_eq!(
+            error,
+            ParseError::new(1, 1, "expected a `# Day N: Title` heading")
+        );
+    }
+
+    #[test]
+    fn should_report_issues() {
+        let questions = [
+            (
+                2015,
+                1,
+                "# Day 1: A\n\nSee [day 2](./2015_02.md) and [day 3](2015_03.md).",
+            ),
+            (2015, 2, "# Day 2: B\n\n```\n(()\n"),
+            (
+                2015,
+                3,
+                "# Day 3: C\n\nSee [the wiki](https://en.wikipedia.org/wiki/Lisp).",
+            ),
+        ];
+        assert_eq!(
+            check_questions(&questions, &[(2015, 1), (2015, 2), (2015, 4)]),
+            vec![
+                Issue::MissingQuestion(2015, 4),
+                Issue::Invalid(
+                    2015,
+                    2,
+                    ParseError::new(5, 1, "expected the end of the example, found end of input")
+                ),
+                Issue::MissingSolver(2015, 3),
+            ]
+        );
+    }
+}
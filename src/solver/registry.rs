@@ -0,0 +1,102 @@
+use super::{Answer, Limits, ParseError, ProgressHook, ProgressUpdate, SolveError, Solver};
+use std::marker::PhantomData;
+
+type BoxedSolver<'a> = Box<dyn Solver<Answer, Answer> + 'a>;
+
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub(super) answer_types: fn() -> [&'static str; 2],
+    pub(super) parse: for<'a> fn(&'a str) -> Result<BoxedSolver<'a>, ParseError>,
+    pub(super) embedded: fn() -> BoxedSolver<'static>,
+}
+
+impl Puzzle {
+    pub fn answer_types(&self) -> [&'static str; 2] {
+        (self.answer_types)()
+    }
+
+    pub fn solver<'a>(&self, input: Option<&'a str>) -> Result<BoxedSolver<'a>, ParseError> {
+        match input {
+            Some(input) => (self.parse)(input.trim_end_matches(['\r', '\n'])),
+            None => Ok((self.embedded)()),
+        }
+    }
+
+    /// Parses `input` and runs `solve` with the solver on a worker thread bound by `limits`.
+    pub fn solve_within<T: Send + 'static>(
+        &'static self,
+        input: Option<&str>,
+        limits: &Limits,
+        solve: impl FnOnce(&dyn Solver<Answer, Answer>, &ProgressHook) -> Result<T, SolveError>
+            + Send
+            + 'static,
+        report: &dyn Fn(ProgressUpdate) -> bool,
+    ) -> Result<T, SolveError> {
+        let input = input.map(String::from);
+        limits.run(
+            move |progress| {
+                let solver = self
+                    .solver(input.as_deref())
+                    .map_err(|error| SolveError::Unsupported(error.to_string()))?;
+                solve(&*solver, progress)
+            },
+            report,
+        )
+    }
+}
+
+struct Erased<S, T1, T2> {
+    solver: S,
+    answers: PhantomData<(T1, T2)>,
+}
+
+impl<S, T1, T2> Solver<Answer, Answer> for Erased<S, T1, T2>
+where
+    S: Solver<T1, T2>,
+    T1: Into<Answer>,
+    T2: Into<Answer>,
+{
+    fn solve_first_part(&self) -> Result<Answer, SolveError> {
+        self.solver.solve_first_part().map(Into::into)
+    }
+
+    fn solve_second_part(&self) -> Result<Answer, SolveError> {
+        self.solver.solve_second_part().map(Into::into)
+    }
+
+    fn solve_first_part_with(&self, progress: &ProgressHook) -> Result<Answer, SolveError> {
+        self.solver.solve_first_part_with(progress).map(Into::into)
+    }
+
+    fn solve_second_part_with(&self, progress: &ProgressHook) -> Result<Answer, SolveError> {
+        self.solver.solve_second_part_with(progress).map(Into::into)
+    }
+}
+
+pub(super) fn erase<'a, S, T1, T2>(solver: S) -> BoxedSolver<'a>
+where
+    S: Solver<T1, T2> + 'a,
+    T1: Into<Answer> + 'a,
+    T2: Into<Answer> + 'a,
+{
+    Box::new(Erased {
+        solver,
+        answers: PhantomData,
+    })
+}
+
+fn type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+pub(super) fn answer_types<S, T1, T2>() -> [&'static str; 2]
+where
+    S: Solver<T1, T2>,
+    T1: Into<Answer>,
+    T2: Into<Answer>,
+{
+    [type_name::<T1>(), type_name::<T2>()]
+}
@@ -1,6 +1,49 @@
-use std::fmt::Display;
+use super::{Answer, ProgressHook};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
 
-pub trait Solver<T1: Display, T2: Display> {
-    fn solve_first_part(&self) -> T1;
-    fn solve_second_part(&self) -> T2;
+#[derive(Debug, PartialEq)]
+pub enum SolveError {
+    NoSolution,
+    Unsupported(String),
+    Cancelled,
+    TimedOut(Duration),
+    OutOfMemory(usize),
+    Panicked(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SolveError::NoSolution => write!(f, "the puzzle has no solution for this input"),
+            SolveError::Unsupported(reason) => write!(f, "unsupported input: {reason}"),
+            SolveError::Cancelled => write!(f, "the solver was cancelled"),
+            SolveError::TimedOut(timeout) => write!(f, "the solver timed out after {timeout:?}"),
+            SolveError::OutOfMemory(budget) => {
+                write!(
+                    f,
+                    "the solver went over its memory budget of {} MB",
+                    budget >> 20
+                )
+            }
+            SolveError::Panicked(message) => write!(f, "the solver panicked: {message}"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+pub trait Solver<T1: Into<Answer>, T2: Into<Answer>> {
+    fn solve_first_part(&self) -> Result<T1, SolveError>;
+    fn solve_second_part(&self) -> Result<T2, SolveError>;
+
+    /// Solves the first part while reporting to `progress`, which only slow solvers bother with.
+    fn solve_first_part_with(&self, _progress: &ProgressHook) -> Result<T1, SolveError> {
+        self.solve_first_part()
+    }
+
+    fn solve_second_part_with(&self, _progress: &ProgressHook) -> Result<T2, SolveError> {
+        self.solve_second_part()
+    }
 }
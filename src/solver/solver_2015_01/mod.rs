@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2015_01<'a> {
     input: &'a str,
@@ -6,29 +6,33 @@ pub struct Solver2015_01<'a> {
 
 impl Default for Solver2015_01<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_01<'a> {
-    fn from(input: &'a str) -> Self {
-        Self { input }
+impl<'a> TryFrom<&'a str> for Solver2015_01<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "()")?;
+        Ok(Self { input })
     }
 }
 
 impl Solver<i32, usize> for Solver2015_01<'_> {
-    fn solve_first_part(&self) -> i32 {
-        self.input
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .input
             .chars()
             .map(|c| match c {
                 '(' => 1,
                 ')' => -1,
                 _ => unreachable!(),
             })
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut score = 0;
 
         for (i, char) in self.input.chars().enumerate() {
@@ -38,11 +42,11 @@ impl Solver<i32, usize> for Solver2015_01<'_> {
                 _ => unreachable!(),
             }
             if score < 0 {
-                return i + 1;
+                return Ok(i + 1);
             }
         }
 
-        unreachable!()
+        Err(SolveError::NoSolution)
     }
 }
 
@@ -51,18 +55,18 @@ mod first_part {
     use super::*;
     #[test]
     fn same_number_of_closing_and_opening_parenthesis() {
-        let solver = Solver2015_01::from("()()");
-        assert_eq!(solver.solve_first_part(), 0);
+        let solver = Solver2015_01::try_from("()()").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 0);
     }
     #[test]
     fn has_more_closing_parenthesis() {
-        let solver = Solver2015_01::from("()()))");
-        assert_eq!(solver.solve_first_part(), -2);
+        let solver = Solver2015_01::try_from("()()))").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), -2);
     }
     #[test]
     fn has_more_opening_parenthesis() {
-        let solver = Solver2015_01::from("()()((");
-        assert_eq!(solver.solve_first_part(), 2);
+        let solver = Solver2015_01::try_from("()()((").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2);
     }
 }
 
@@ -71,17 +75,22 @@ mod second_part {
     use super::*;
     #[test]
     fn enter_basement_with_first_character() {
-        let solver = Solver2015_01::from(")");
-        assert_eq!(solver.solve_second_part(), 1);
+        let solver = Solver2015_01::try_from(")").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 1);
     }
     #[test]
     fn enter_basement_with_last_character() {
-        let solver = Solver2015_01::from("(()))");
-        assert_eq!(solver.solve_second_part(), 5);
+        let solver = Solver2015_01::try_from("(()))").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 5);
     }
     #[test]
     fn enter_basement_in_the_middle() {
-        let solver = Solver2015_01::from("()))()())()())");
-        assert_eq!(solver.solve_second_part(), 3);
+        let solver = Solver2015_01::try_from("()))()())()())").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 3);
+    }
+    #[test]
+    fn never_enter_basement() {
+        let solver = Solver2015_01::try_from("(((").unwrap();
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 struct RectangularPrism {
     width: i32,
@@ -47,40 +47,49 @@ pub struct Solver2015_02 {
 
 impl Default for Solver2015_02 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl From<&str> for Solver2015_02 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2015_02 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
             rectangle_prisms: input
                 .split("\n")
                 .map(|row| {
-                    let edges: Vec<i32> =
-                        row.split("x").map(|edge| edge.parse().unwrap()).collect();
-                    RectangularPrism::new(edges[0], edges[1], edges[2])
+                    let edges = row
+                        .split("x")
+                        .map(|edge| parse::value(input, edge))
+                        .collect::<Result<Vec<i32>, _>>()?;
+                    match edges[..] {
+                        [width, length, height] => Ok(RectangularPrism::new(width, length, height)),
+                        _ => Err(ParseError::at(input, row, "expected `LxWxH`")),
+                    }
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
 impl Solver<i32, i32> for Solver2015_02 {
-    fn solve_first_part(&self) -> i32 {
-        self.rectangle_prisms
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .rectangle_prisms
             .iter()
             .map(|rectangle_prism| {
                 rectangle_prism.get_area() + rectangle_prism.get_smallest_area_of_sides()
             })
-            .sum()
+            .sum())
     }
-    fn solve_second_part(&self) -> i32 {
-        self.rectangle_prisms
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .rectangle_prisms
             .iter()
             .map(|rectangle_prism| {
                 rectangle_prism.get_volume() + rectangle_prism.get_smallest_perimeter_of_sides()
             })
-            .sum()
+            .sum())
     }
 }
 
@@ -90,13 +99,13 @@ mod first_part {
 
     #[test]
     fn first_example() {
-        let solver = Solver2015_02::from("2x3x4");
-        assert_eq!(solver.solve_first_part(), 58);
+        let solver = Solver2015_02::try_from("2x3x4").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 58);
     }
     #[test]
     fn second_example() {
-        let solver = Solver2015_02::from("1x1x10");
-        assert_eq!(solver.solve_first_part(), 43);
+        let solver = Solver2015_02::try_from("1x1x10").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 43);
     }
 }
 
@@ -106,12 +115,12 @@ mod second_part {
 
     #[test]
     fn first_example() {
-        let solver = Solver2015_02::from("2x3x4");
-        assert_eq!(solver.solve_second_part(), 34);
+        let solver = Solver2015_02::try_from("2x3x4").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 34);
     }
     #[test]
     fn second_example() {
-        let solver = Solver2015_02::from("1x1x10");
-        assert_eq!(solver.solve_second_part(), 14);
+        let solver = Solver2015_02::try_from("1x1x10").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 14);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::HashSet;
 
 #[derive(Eq, Hash, PartialEq, Copy, Clone)]
@@ -57,18 +57,21 @@ pub struct Solver2015_03<'a> {
 
 impl Default for Solver2015_03<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_03<'a> {
-    fn from(input: &'a str) -> Self {
-        Self { directions: input }
+impl<'a> TryFrom<&'a str> for Solver2015_03<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "<>^v")?;
+        Ok(Self { directions: input })
     }
 }
 
 impl Solver<usize, usize> for Solver2015_03<'_> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut locations: HashSet<Location> = HashSet::new();
         let mut location = Location::new();
 
@@ -78,10 +81,10 @@ impl Solver<usize, usize> for Solver2015_03<'_> {
             location = location.to(direction);
             locations.insert(location);
         }
-        locations.len()
+        Ok(locations.len())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut locations: HashSet<Location> = HashSet::new();
 
         let mut santa_location = Location::new();
@@ -98,7 +101,7 @@ impl Solver<usize, usize> for Solver2015_03<'_> {
             }
             santa_turn = !santa_turn;
         }
-        locations.len()
+        Ok(locations.len())
     }
 }
 
@@ -108,18 +111,18 @@ mod first_part {
 
     #[test]
     fn first_example() {
-        let solver = Solver2015_03::from(">");
-        assert_eq!(solver.solve_first_part(), 2);
+        let solver = Solver2015_03::try_from(">").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2);
     }
     #[test]
     fn second_example() {
-        let solver = Solver2015_03::from("^>v<");
-        assert_eq!(solver.solve_first_part(), 4);
+        let solver = Solver2015_03::try_from("^>v<").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 4);
     }
     #[test]
     fn third_example() {
-        let solver = Solver2015_03::from("^v^v^v^v^v");
-        assert_eq!(solver.solve_first_part(), 2);
+        let solver = Solver2015_03::try_from("^v^v^v^v^v").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2);
     }
 }
 
@@ -129,17 +132,17 @@ mod second_part {
 
     #[test]
     fn first_example() {
-        let solver = Solver2015_03::from("^v");
-        assert_eq!(solver.solve_second_part(), 3);
+        let solver = Solver2015_03::try_from("^v").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 3);
     }
     #[test]
     fn second_example() {
-        let solver = Solver2015_03::from("^>v<");
-        assert_eq!(solver.solve_second_part(), 3);
+        let solver = Solver2015_03::try_from("^>v<").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 3);
     }
     #[test]
     fn third_example() {
-        let solver = Solver2015_03::from("^v^v^v^v^v");
-        assert_eq!(solver.solve_second_part(), 11);
+        let solver = Solver2015_03::try_from("^v^v^v^v^v").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 11);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, ProgressHook, ProgressUpdate, SolveError, Solver};
 use md5;
 
 pub struct Solver2015_04<'a> {
@@ -7,37 +7,50 @@ pub struct Solver2015_04<'a> {
 
 impl Default for Solver2015_04<'_> {
     fn default() -> Self {
-        Self::from("bgvyzdsv")
+        Self::try_from("bgvyzdsv").unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_04<'a> {
-    fn from(input: &'a str) -> Self {
-        Self { input }
+impl<'a> TryFrom<&'a str> for Solver2015_04<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self { input })
     }
 }
 
-impl Solver<u32, u32> for Solver2015_04<'_> {
-    fn solve_first_part(&self) -> u32 {
+impl Solver2015_04<'_> {
+    fn mine(&self, zeros: &str, progress: &ProgressHook) -> Result<u32, SolveError> {
         let mut number: u32 = 0;
         loop {
             let hash = md5::compute(format!("{}{}", self.input, number));
-            if format!("{:x}", hash).starts_with("00000") {
-                return number;
+            if format!("{:x}", hash).starts_with(zeros) {
+                return Ok(number);
             }
+            progress.report(|| ProgressUpdate {
+                states: number.into(),
+                ..Default::default()
+            })?;
             number += 1;
         }
     }
+}
 
-    fn solve_second_part(&self) -> u32 {
-        let mut number: u32 = 0;
-        loop {
-            let hash = md5::compute(format!("{}{}", self.input, number));
-            if format!("{:x}", hash).starts_with("000000") {
-                return number;
-            }
-            number += 1;
-        }
+impl Solver<u32, u32> for Solver2015_04<'_> {
+    fn solve_first_part(&self) -> Result<u32, SolveError> {
+        self.solve_first_part_with(&ProgressHook::none())
+    }
+
+    fn solve_second_part(&self) -> Result<u32, SolveError> {
+        self.solve_second_part_with(&ProgressHook::none())
+    }
+
+    fn solve_first_part_with(&self, progress: &ProgressHook) -> Result<u32, SolveError> {
+        self.mine("00000", progress)
+    }
+
+    fn solve_second_part_with(&self, progress: &ProgressHook) -> Result<u32, SolveError> {
+        self.mine("000000", progress)
     }
 }
 
@@ -46,12 +59,12 @@ mod first_part {
     use super::*;
     #[test]
     fn solve_first_example() {
-        let solver = Solver2015_04::from("abcdef");
-        assert_eq!(solver.solve_first_part(), 609043);
+        let solver = Solver2015_04::try_from("abcdef").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 609043);
     }
     #[test]
     fn solve_second_example() {
-        let solver = Solver2015_04::from("pqrstuv");
-        assert_eq!(solver.solve_first_part(), 1048970);
+        let solver = Solver2015_04::try_from("pqrstuv").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 1048970);
     }
 }
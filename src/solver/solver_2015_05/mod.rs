@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use regex::Regex;
 
 fn does_not_contain_forbidden_words(input: &str) -> bool {
@@ -54,32 +54,36 @@ pub struct Solver2015_05<'a> {
 
 impl Default for Solver2015_05<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl<'a> From<&'a str> for Solver2015_05<'a> {
-    fn from(input: &'a str) -> Self {
-        Self {
+impl<'a> TryFrom<&'a str> for Solver2015_05<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
             words: input.lines().collect(),
-        }
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2015_05<'_> {
-    fn solve_first_part(&self) -> usize {
-        self.words
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .words
             .iter()
             .filter(|word| is_nice_string(word))
-            .count()
+            .count())
     }
-    fn solve_second_part(&self) -> usize {
-        self.words
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .words
             .iter()
             .filter(|word| {
                 does_contain_same_characters_with_one_space(word)
                     && does_contain_repeating_two_letters(word)
             })
-            .count()
+            .count())
     }
 }
 
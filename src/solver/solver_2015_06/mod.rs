@@ -1,5 +1,5 @@
-use super::Solver;
-use regex::{Captures, Regex};
+use super::{parse, ParseError, SolveError, Solver};
+use regex::Regex;
 
 enum Command {
     On,
@@ -19,39 +19,50 @@ pub struct Solver2015_06 {
 
 impl Default for Solver2015_06 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl<'a> From<&'a str> for Solver2015_06 {
-    fn from(input: &'a str) -> Self {
-        let re = Regex::new(r"(turn on|turn off|toggle) (\d+),(\d+) through (\d+),(\d+)").unwrap();
+impl<'a> TryFrom<&'a str> for Solver2015_06 {
+    type Error = ParseError;
 
-        Self {
-            instructions: re
-                .captures_iter(input)
-                .map(|captures: Captures| Instruction {
-                    start: (
-                        (&captures)[2].parse().unwrap(),
-                        (&captures)[3].parse().unwrap(),
-                    ),
-                    end: (
-                        (&captures)[4].parse().unwrap(),
-                        (&captures)[5].parse().unwrap(),
-                    ),
-                    command: match &(&captures)[1] {
-                        "turn on" => Command::On,
-                        "turn off" => Command::Off,
-                        "toggle" => Command::Toggle,
-                        other => panic!("Illegal argument: {}", other),
-                    },
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let re =
+            Regex::new(r"^(turn on|turn off|toggle) (\d+),(\d+) through (\d+),(\d+)$").unwrap();
+
+        let coordinate = |span: &str| {
+            let coordinate: usize = parse::value(input, span)?;
+            if coordinate < 1000 {
+                Ok(coordinate)
+            } else {
+                Err(ParseError::at(
+                    input,
+                    span,
+                    "the grid is only 1000 lights wide",
+                ))
+            }
+        };
+        Ok(Self {
+            instructions: input
+                .lines()
+                .map(|line| {
+                    let captures = parse::captures(input, &re, line)?;
+                    Ok(Instruction {
+                        start: (coordinate(&captures[2])?, coordinate(&captures[3])?),
+                        end: (coordinate(&captures[4])?, coordinate(&captures[5])?),
+                        command: match &captures[1] {
+                            "turn on" => Command::On,
+                            "turn off" => Command::Off,
+                            _ => Command::Toggle,
+                        },
+                    })
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2015_06 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut lights = [[false; 1000]; 1000];
 
         for instruction in self.instructions.iter() {
@@ -66,12 +77,12 @@ impl Solver<usize, usize> for Solver2015_06 {
             }
         }
 
-        lights
+        Ok(lights
             .iter()
             .map(|row: &[bool; 1000]| row.iter().filter(|light| **light).count())
-            .sum()
+            .sum())
     }
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut lights = [[0usize; 1000]; 1000];
 
         for instruction in self.instructions.iter() {
@@ -86,9 +97,9 @@ impl Solver<usize, usize> for Solver2015_06 {
             }
         }
 
-        lights
+        Ok(lights
             .iter()
             .map(|row: &[usize; 1000]| row.iter().sum::<usize>())
-            .sum()
+            .sum())
     }
 }
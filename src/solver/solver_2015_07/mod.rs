@@ -1,7 +1,7 @@
-use crate::solver::Solver;
+use crate::solver::{parse, ParseError, SolveError, Solver};
 use pest::iterators::Pair;
 use pest::Parser;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(pest_derive::Parser)]
 #[grammar = "solver/solver_2015_07/grammar.pest"]
@@ -12,11 +12,13 @@ enum Expr<'a> {
     Number(u16),
 }
 
-impl<'a> From<Pair<'a, Rule>> for Expr<'a> {
-    fn from(pair: Pair<'a, Rule>) -> Self {
+impl<'a> TryFrom<Pair<'a, Rule>> for Expr<'a> {
+    type Error = ParseError;
+
+    fn try_from(pair: Pair<'a, Rule>) -> Result<Self, Self::Error> {
         match pair.as_rule() {
-            Rule::Ident => Self::Ident(pair.as_str()),
-            Rule::Number => Self::Number(pair.as_str().parse().unwrap()),
+            Rule::Ident => Ok(Self::Ident(pair.as_str())),
+            Rule::Number => Ok(Self::Number(parse::pair_value(&pair)?)),
             _ => unreachable!(),
         }
     }
@@ -31,16 +33,37 @@ enum Statement<'a> {
     Assign(Expr<'a>),
 }
 
-impl<'a> From<Pair<'a, Rule>> for Statement<'a> {
-    fn from(pair: Pair<'a, Rule>) -> Self {
+impl<'a> Statement<'a> {
+    fn operands(&self) -> Vec<&Expr<'a>> {
+        match self {
+            Self::And(lhs, rhs)
+            | Self::Or(lhs, rhs)
+            | Self::LShift(lhs, rhs)
+            | Self::RShift(lhs, rhs) => vec![lhs, rhs],
+            Self::Not(expr) | Self::Assign(expr) => vec![expr],
+        }
+    }
+
+    fn wires(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.operands().into_iter().filter_map(|expr| match expr {
+            Expr::Ident(wire) => Some(*wire),
+            Expr::Number(_) => None,
+        })
+    }
+}
+
+impl<'a> TryFrom<Pair<'a, Rule>> for Statement<'a> {
+    type Error = ParseError;
+
+    fn try_from(pair: Pair<'a, Rule>) -> Result<Self, Self::Error> {
         let inner: Vec<Pair<Rule>> = pair.into_inner().collect();
-        if inner.len() == 1 {
-            Self::Assign(inner[0].clone().into())
+        Ok(if inner.len() == 1 {
+            Self::Assign(inner[0].clone().try_into()?)
         } else if inner.len() == 2 {
-            Self::Not(inner[1].clone().into())
+            Self::Not(inner[1].clone().try_into()?)
         } else if inner.len() == 3 {
-            let lhs = inner[0].clone().into();
-            let rhs = inner[2].clone().into();
+            let lhs = inner[0].clone().try_into()?;
+            let rhs = inner[2].clone().try_into()?;
             match inner[1].as_rule() {
                 Rule::And => Self::And(lhs, rhs),
                 Rule::Or => Self::Or(lhs, rhs),
@@ -50,7 +73,7 @@ impl<'a> From<Pair<'a, Rule>> for Statement<'a> {
             }
         } else {
             unreachable!()
-        }
+        })
     }
 }
 
@@ -61,27 +84,74 @@ pub struct Solver2015_07<'a> {
 
 impl Default for Solver2015_07<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl<'a> From<&'a str> for Solver2015_07<'a> {
-    fn from(input: &'a str) -> Self {
-        let pairs = SantaParser::parse(Rule::Program, input).unwrap();
+impl<'a> TryFrom<&'a str> for Solver2015_07<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let pairs = SantaParser::parse(Rule::Program, input)?;
         let program = pairs.peek().unwrap();
         let mut statements = HashMap::<&str, Statement<'a>>::new();
-        for statement in program.into_inner() {
+        for statement in program
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::Statement)
+        {
             let rules: Vec<Pair<Rule>> = statement.into_inner().collect();
             let rhs = rules[1].as_str();
-            statements.insert(rhs, rules[0].clone().into());
+            statements.insert(rhs, rules[0].clone().try_into()?);
         }
-        Self {
+        if let Some(wire) = statements
+            .values()
+            .flat_map(Statement::wires)
+            .filter(|wire| !statements.contains_key(wire))
+            .min_by_key(|wire| wire.as_ptr())
+        {
+            return Err(ParseError::at(
+                input,
+                wire,
+                format!("the wire `{wire}` has no signal"),
+            ));
+        }
+        // Wires are evaluated recursively, so a loop among them would never end.
+        let mut resolved = HashSet::new();
+        let mut pending: Vec<&str> = statements.keys().copied().collect();
+        while !pending.is_empty() {
+            let count = pending.len();
+            pending.retain(|wire| {
+                if statements[wire].wires().all(|wire| resolved.contains(wire)) {
+                    resolved.insert(*wire);
+                    return false;
+                }
+                true
+            });
+            if pending.len() == count {
+                let wire = pending.iter().min_by_key(|wire| wire.as_ptr()).unwrap();
+                return Err(ParseError::at(
+                    input,
+                    wire,
+                    format!("the wire `{wire}` depends on itself"),
+                ));
+            }
+        }
+        Ok(Self {
             statements,
             variable: "a",
-        }
+        })
     }
 }
 
 impl<'a> Solver2015_07<'a> {
+    fn check_wire(&self, wire: &str) -> Result<(), SolveError> {
+        if !self.statements.contains_key(wire) {
+            return Err(SolveError::Unsupported(format!(
+                "there is no wire `{wire}`"
+            )));
+        }
+        Ok(())
+    }
+
     fn evaluate_expr(&'a self, expr: &'a Expr, value_map: &mut HashMap<&'a str, u16>) -> u16 {
         match expr {
             Expr::Ident(variable) => self.evaluate_ident(variable, value_map),
@@ -97,7 +167,7 @@ impl<'a> Solver2015_07<'a> {
         if let Some(&result) = value_map.get(variable) {
             return result;
         }
-        let result = match self.statements.get(variable).unwrap() {
+        let result = match &self.statements[variable] {
             Statement::Assign(exp) => self.evaluate_expr(exp, value_map),
             Statement::And(lhs, rhs) => {
                 self.evaluate_expr(lhs, value_map) & self.evaluate_expr(rhs, value_map)
@@ -106,10 +176,14 @@ impl<'a> Solver2015_07<'a> {
                 self.evaluate_expr(lhs, value_map) | self.evaluate_expr(rhs, value_map)
             }
             Statement::LShift(lhs, rhs) => {
-                self.evaluate_expr(lhs, value_map) << self.evaluate_expr(rhs, value_map)
+                let lhs = self.evaluate_expr(lhs, value_map);
+                lhs.checked_shl(self.evaluate_expr(rhs, value_map).into())
+                    .unwrap_or(0)
             }
             Statement::RShift(lhs, rhs) => {
-                self.evaluate_expr(lhs, value_map) >> self.evaluate_expr(rhs, value_map)
+                let lhs = self.evaluate_expr(lhs, value_map);
+                lhs.checked_shr(self.evaluate_expr(rhs, value_map).into())
+                    .unwrap_or(0)
             }
             Statement::Not(expr) => !self.evaluate_expr(expr, value_map),
         };
@@ -119,16 +193,18 @@ impl<'a> Solver2015_07<'a> {
 }
 
 impl Solver<u16, u16> for Solver2015_07<'_> {
-    fn solve_first_part(&self) -> u16 {
+    fn solve_first_part(&self) -> Result<u16, SolveError> {
+        self.check_wire(self.variable)?;
         let mut value_map = HashMap::new();
-        self.evaluate_ident(self.variable, &mut value_map)
+        Ok(self.evaluate_ident(self.variable, &mut value_map))
     }
-    fn solve_second_part(&self) -> u16 {
+    fn solve_second_part(&self) -> Result<u16, SolveError> {
+        self.check_wire("a")?;
         let mut value_map = HashMap::new();
         let previous_value = self.evaluate_ident("a", &mut value_map);
         let mut value_map = HashMap::new();
         value_map.insert("b", previous_value);
-        self.evaluate_ident("a", &mut value_map)
+        Ok(self.evaluate_ident("a", &mut value_map))
     }
 }
 
@@ -138,50 +214,66 @@ mod find_start_of_message_marker {
     static EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn should_solve_x_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "x";
-        assert_eq!(solver.solve_first_part(), 123);
+        assert_eq!(solver.solve_first_part().unwrap(), 123);
     }
     #[test]
     fn should_solve_y_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "y";
-        assert_eq!(solver.solve_first_part(), 456);
+        assert_eq!(solver.solve_first_part().unwrap(), 456);
     }
     #[test]
     fn should_solve_h_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "h";
-        assert_eq!(solver.solve_first_part(), 65412);
+        assert_eq!(solver.solve_first_part().unwrap(), 65412);
     }
     #[test]
     fn should_solve_i_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "i";
-        assert_eq!(solver.solve_first_part(), 65079);
+        assert_eq!(solver.solve_first_part().unwrap(), 65079);
     }
     #[test]
     fn should_solve_d_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "d";
-        assert_eq!(solver.solve_first_part(), 72);
+        assert_eq!(solver.solve_first_part().unwrap(), 72);
     }
     #[test]
     fn should_solve_e_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "e";
-        assert_eq!(solver.solve_first_part(), 507);
+        assert_eq!(solver.solve_first_part().unwrap(), 507);
     }
     #[test]
     fn should_solve_f_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "f";
-        assert_eq!(solver.solve_first_part(), 492);
+        assert_eq!(solver.solve_first_part().unwrap(), 492);
+    }
+    #[test]
+    fn should_reject_wire_without_signal() {
+        assert_eq!(
+            Solver2015_07::try_from("a -> b\nc -> d").err(),
+            Some(ParseError::new(1, 1, "the wire `a` has no signal"))
+        );
+        assert_eq!(
+            Solver2015_07::try_from("1 -> c\nb -> a\na AND c -> b").err(),
+            Some(ParseError::new(2, 6, "the wire `a` depends on itself"))
+        );
+        let solver = Solver2015_07::try_from("1 -> b").unwrap();
+        assert_eq!(
+            solver.solve_first_part(),
+            Err(SolveError::Unsupported("there is no wire `a`".to_string()))
+        );
     }
     #[test]
     fn should_solve_g_in_example() {
-        let mut solver = Solver2015_07::from(EXAMPLE);
+        let mut solver = Solver2015_07::try_from(EXAMPLE).unwrap();
         solver.variable = "g";
-        assert_eq!(solver.solve_first_part(), 114);
+        assert_eq!(solver.solve_first_part().unwrap(), 114);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 
 pub struct Solver2015_08 {
     strings: Vec<String>,
@@ -6,29 +6,42 @@ pub struct Solver2015_08 {
 
 impl Default for Solver2015_08 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_08 {
-    fn from(input: &str) -> Self {
-        Self {
-            strings: input.lines().map(|s| s.to_string()).collect(),
-        }
+impl TryFrom<&str> for Solver2015_08 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            strings: input
+                .lines()
+                .map(|s| {
+                    if s.len() >= 2 && s.starts_with('"') && s.ends_with('"') {
+                        Ok(s.to_string())
+                    } else {
+                        Err(ParseError::at(input, s, "expected a string literal"))
+                    }
+                })
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2015_08 {
-    fn solve_first_part(&self) -> usize {
-        self.strings
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .strings
             .iter()
-            .fold(0, |acc, s| acc + s.len() - count_memory_chars(s))
+            .fold(0, |acc, s| acc + s.len() - count_memory_chars(s)))
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.strings
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .strings
             .iter()
-            .fold(0, |acc, s| acc + count_encoded_chars(s) - s.len())
+            .fold(0, |acc, s| acc + count_encoded_chars(s) - s.len()))
     }
 }
 
@@ -71,13 +84,13 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_08::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 12);
+        let solver = Solver2015_08::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 12);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2015_08::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 19);
+        let solver = Solver2015_08::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 19);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
@@ -10,24 +10,29 @@ pub struct Solver2015_09<'a> {
 
 impl Default for Solver2015_09<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_09<'a> {
-    fn from(input: &'a str) -> Self {
-        let re = Regex::new(r"(?P<city1>\w+) to (?P<city2>\w+) = (?P<distance>\d+)").unwrap();
+impl<'a> TryFrom<&'a str> for Solver2015_09<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let re = Regex::new(r"^(?P<city1>\w+) to (?P<city2>\w+) = (?P<distance>\d+)$").unwrap();
         let distances: HashMap<(&str, &str), usize> = input
             .lines()
             .map(|s| {
-                let captures = re.captures(s).unwrap();
+                let captures = parse::captures(input, &re, s)?;
 
                 let city1 = captures.name("city1").unwrap().as_str();
                 let city2 = captures.name("city2").unwrap().as_str();
-                let distance = captures.name("distance").unwrap().as_str().parse().unwrap();
-                ((city1, city2), distance)
+                let distance = parse::value(input, captures.name("distance").unwrap().as_str())?;
+                Ok(((city1, city2), distance))
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
+        if distances.is_empty() {
+            return Err(ParseError::end_of_input(input, "a distance"));
+        }
         let mut neighbors: HashMap<&str, HashSet<&str>> = HashMap::new();
         let mut all_cities: HashSet<&str> = HashSet::new();
         for (&(a, b), _) in distances.iter() {
@@ -36,11 +41,11 @@ impl<'a> From<&'a str> for Solver2015_09<'a> {
             all_cities.insert(a);
             all_cities.insert(b);
         }
-        Self {
+        Ok(Self {
             distances,
             neighbors,
             all_cities,
-        }
+        })
     }
 }
 
@@ -107,7 +112,7 @@ impl<'a> Solver2015_09<'a> {
 }
 
 impl Solver<usize, usize> for Solver2015_09<'_> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         self.all_cities
             .iter()
             .filter_map(|&city| {
@@ -116,10 +121,10 @@ impl Solver<usize, usize> for Solver2015_09<'_> {
                 self.get_shortest_distance(city, &mut visited)
             })
             .min()
-            .unwrap()
+            .ok_or(SolveError::NoSolution)
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         self.all_cities
             .iter()
             .filter_map(|&city| {
@@ -128,7 +133,7 @@ impl Solver<usize, usize> for Solver2015_09<'_> {
                 self.get_longest_distance(city, &mut visited)
             })
             .max()
-            .unwrap()
+            .ok_or(SolveError::NoSolution)
     }
 }
 
@@ -140,13 +145,32 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_09::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 605);
+        let solver = Solver2015_09::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 605);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2015_09::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 982);
+        let solver = Solver2015_09::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 982);
+    }
+
+    #[test]
+    fn should_fail_without_route_through_every_city() {
+        let solver = Solver2015_09::try_from("A to B = 1\nC to D = 2").unwrap();
+        assert_eq!(solver.solve_first_part(), Err(SolveError::NoSolution));
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
+    }
+
+    #[test]
+    fn should_reject_empty_input() {
+        assert_eq!(
+            Solver2015_09::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a distance, found end of input"
+            ))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2015_10 {
     input: Vec<u8>,
@@ -6,18 +6,24 @@ pub struct Solver2015_10 {
 
 impl Default for Solver2015_10 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_10 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2015_10 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "0123456789")?;
+        if input.is_empty() {
+            return Err(ParseError::end_of_input(input, "a digit"));
+        }
+        Ok(Self {
             input: input
                 .chars()
                 .map(|c| c.to_digit(10).unwrap() as u8)
                 .collect(),
-        }
+        })
     }
 }
 
@@ -63,12 +69,12 @@ impl Solver2015_10 {
 }
 
 impl Solver<usize, usize> for Solver2015_10 {
-    fn solve_first_part(&self) -> usize {
-        self.apply_look_and_say_n_times(40)
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self.apply_look_and_say_n_times(40))
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.apply_look_and_say_n_times(50)
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self.apply_look_and_say_n_times(50))
     }
 }
 
@@ -0,0 +1,154 @@
+use super::{parse, ParseError, SolveError, Solver};
+use std::fmt::{Display, Formatter};
+
+const FORBIDDEN: [u8; 3] = [b'i' - b'a', b'o' - b'a', b'l' - b'a'];
+
+/// A password as a base-26 number, with `a` as 0 and the last letter as the least significant.
+#[derive(Debug, Clone, PartialEq)]
+struct Password {
+    letters: Vec<u8>,
+}
+
+impl Password {
+    fn has_straight(&self) -> bool {
+        self.letters
+            .windows(3)
+            .any(|run| run[1] == run[0] + 1 && run[2] == run[1] + 1)
+    }
+
+    // Pairs of the same letter can only overlap each other, so two letters means two pairs.
+    fn has_two_pairs(&self) -> bool {
+        let pairs = self
+            .letters
+            .windows(2)
+            .filter(|pair| pair[0] == pair[1])
+            .fold(0u32, |pairs, pair| pairs | 1 << pair[0]);
+        pairs.count_ones() >= 2
+    }
+
+    fn is_valid(&self) -> bool {
+        !self.letters.iter().any(|letter| FORBIDDEN.contains(letter))
+            && self.has_straight()
+            && self.has_two_pairs()
+    }
+
+    /// Moves to the next password without forbidden letters, carrying into the letters on the left.
+    fn increment(&mut self) {
+        // Every password after the first forbidden letter still contains it, so they are skipped
+        // at once by bumping the letter and restarting the ones after it from `a`.
+        if let Some(i) = self
+            .letters
+            .iter()
+            .position(|letter| FORBIDDEN.contains(letter))
+        {
+            self.letters[i] += 1;
+            self.letters[i + 1..].fill(0);
+            return;
+        }
+        for letter in self.letters.iter_mut().rev() {
+            if *letter == 25 {
+                *letter = 0;
+                continue;
+            }
+            *letter += 1;
+            if FORBIDDEN.contains(letter) {
+                *letter += 1;
+            }
+            break;
+        }
+    }
+
+    fn next_valid(&self) -> Password {
+        let mut password = self.clone();
+        password.increment();
+        while !password.is_valid() {
+            password.increment();
+        }
+        password
+    }
+}
+
+impl Display for Password {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.letters
+            .iter()
+            .try_for_each(|letter| write!(f, "{}", (b'a' + letter) as char))
+    }
+}
+
+pub struct Solver2015_11 {
+    password: Password,
+}
+
+impl Default for Solver2015_11 {
+    fn default() -> Self {
+        Self::try_from(include_str!("input.txt")).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Solver2015_11 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "abcdefghijklmnopqrstuvwxyz")?;
+        // Shorter passwords can't hold a straight next to two pairs, so none of them is valid.
+        if input.len() < 5 {
+            return Err(ParseError::end_of_input(
+                input,
+                "a password of 5 letters or more",
+            ));
+        }
+        Ok(Self {
+            password: Password {
+                letters: input.bytes().map(|letter| letter - b'a').collect(),
+            },
+        })
+    }
+}
+
+impl Solver<String, String> for Solver2015_11 {
+    fn solve_first_part(&self) -> Result<String, SolveError> {
+        Ok(self.password.next_valid().to_string())
+    }
+
+    fn solve_second_part(&self) -> Result<String, SolveError> {
+        Ok(self.password.next_valid().next_valid().to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn password(text: &str) -> Password {
+        Solver2015_11::try_from(text).unwrap().password
+    }
+
+    #[test]
+    fn should_check_rules() {
+        assert!(!password("hijklmmn").is_valid());
+        assert!(!password("abbceffg").is_valid());
+        assert!(!password("abbcegjk").is_valid());
+        assert!(password("abcdffaa").is_valid());
+        assert!(!password("abcdfffa").is_valid());
+    }
+
+    #[test]
+    fn should_increment_with_carry() {
+        let mut next = password("aaaxz");
+        next.increment();
+        assert_eq!(next.to_string(), "aaaya");
+        next = password("aahzz");
+        next.increment();
+        assert_eq!(next.to_string(), "aajaa");
+        next = password("ghijklmn");
+        next.increment();
+        assert_eq!(next.to_string(), "ghjaaaaa");
+    }
+
+    #[test]
+    fn should_find_next_password() {
+        assert_eq!(password("abcdefgh").next_valid().to_string(), "abcdffaa");
+        assert_eq!(password("ghijklmn").next_valid().to_string(), "ghjaabcc");
+    }
+}
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 
 pub struct Solver2015_12 {
     value: serde_json::Value,
@@ -6,46 +6,61 @@ pub struct Solver2015_12 {
 
 impl Default for Solver2015_12 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_12 {
-    fn from(input: &str) -> Self {
-        Self {
-            value: serde_json::from_str(input).unwrap(),
-        }
+impl TryFrom<&str> for Solver2015_12 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let value = serde_json::from_str(input).map_err(|error| {
+            let message = error.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            ParseError::new(error.line(), error.column(), message)
+        })?;
+        Ok(Self { value })
     }
 }
 
-fn get_total(value: &serde_json::Value, ignore: Option<&str>) -> i64 {
+fn get_sum<'a>(
+    mut values: impl Iterator<Item = &'a serde_json::Value>,
+    ignore: Option<&str>,
+) -> Result<i64, SolveError> {
+    values.try_fold(0i64, |total, value| {
+        total
+            .checked_add(get_total(value, ignore)?)
+            .ok_or_else(|| SolveError::Unsupported("the total overflows 64 bits".to_string()))
+    })
+}
+
+fn get_total(value: &serde_json::Value, ignore: Option<&str>) -> Result<i64, SolveError> {
     match value {
-        serde_json::Value::Number(number) => number.as_i64().unwrap(),
-        serde_json::Value::Array(array) => array.iter().map(|value| get_total(value, ignore)).sum(),
+        serde_json::Value::Number(number) => number.as_i64().ok_or_else(|| {
+            SolveError::Unsupported(format!("{number} is not an integer of 64 bits"))
+        }),
+        serde_json::Value::Array(array) => get_sum(array.iter(), ignore),
         serde_json::Value::Object(object) => {
             if ignore.is_some_and(|ignore| {
                 object
                     .values()
                     .any(|v| v.as_str().is_some_and(|s| s == ignore))
             }) {
-                0
+                Ok(0)
             } else {
-                object
-                    .iter()
-                    .map(|(_, value)| get_total(value, ignore))
-                    .sum()
+                get_sum(object.values(), ignore)
             }
         }
-        _ => 0,
+        _ => Ok(0),
     }
 }
 
 impl Solver<i64, i64> for Solver2015_12 {
-    fn solve_first_part(&self) -> i64 {
+    fn solve_first_part(&self) -> Result<i64, SolveError> {
         get_total(&self.value, None)
     }
 
-    fn solve_second_part(&self) -> i64 {
+    fn solve_second_part(&self) -> Result<i64, SolveError> {
         get_total(&self.value, Some("red"))
     }
 }
@@ -58,13 +73,31 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_12::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 6);
+        let solver = Solver2015_12::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 6);
+    }
+
+    #[test]
+    fn should_fail_on_numbers_that_are_not_integers() {
+        for (input, error) in [
+            ("[1.5]", "1.5 is not an integer of 64 bits"),
+            (
+                "[99999999999999999999999]",
+                "1e23 is not an integer of 64 bits",
+            ),
+            ("[9223372036854775807, 1]", "the total overflows 64 bits"),
+        ] {
+            let solver = Solver2015_12::try_from(input).unwrap();
+            assert_eq!(
+                solver.solve_first_part(),
+                Err(SolveError::Unsupported(error.to_string()))
+            );
+        }
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2015_12::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 4);
+        let solver = Solver2015_12::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 4);
     }
 }
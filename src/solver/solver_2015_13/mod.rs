@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::{HashMap, HashSet};
 
 pub struct Solver2015_13<'a> {
@@ -8,38 +8,43 @@ pub struct Solver2015_13<'a> {
 
 impl Default for Solver2015_13<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_13<'a> {
-    fn from(input: &'a str) -> Self {
-        let re = regex::Regex::new(r"(?P<first>\w+) would (?P<action>gain|lose) (?P<value>\d+) happiness units by sitting next to (?P<second>\w+).").unwrap();
+impl<'a> TryFrom<&'a str> for Solver2015_13<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let re = regex::Regex::new(r"^(?P<first>\w+) would (?P<action>gain|lose) (?P<value>\d+) happiness units by sitting next to (?P<second>\w+).$").unwrap();
         let mut people: HashSet<&str> = HashSet::new();
         let happiness_scores: HashMap<(&'a str, &'a str), isize> = input
             .lines()
             .map(|line| {
-                let captures = re.captures(line).unwrap();
-                let score: isize = captures.name("value").unwrap().as_str().parse().unwrap();
+                let captures = parse::captures(input, &re, line)?;
+                let score: isize = parse::value(input, captures.name("value").unwrap().as_str())?;
                 let first = captures.name("first").unwrap().as_str();
                 let second = captures.name("second").unwrap().as_str();
                 people.insert(first);
                 people.insert(second);
-                (
+                Ok((
                     (first, second),
                     if captures.name("action").unwrap().as_str() == "gain" {
                         score
                     } else {
                         -score
                     },
-                )
+                ))
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
+        if people.is_empty() {
+            return Err(ParseError::end_of_input(input, "a happiness change"));
+        }
 
-        Self {
+        Ok(Self {
             happiness_scores,
             people,
-        }
+        })
     }
 }
 
@@ -79,18 +84,18 @@ impl<'a> Solver2015_13<'a> {
 }
 
 impl Solver<isize, isize> for Solver2015_13<'_> {
-    fn solve_first_part(&self) -> isize {
+    fn solve_first_part(&self) -> Result<isize, SolveError> {
         let &first = self.people.iter().next().unwrap();
         let mut placed = HashSet::new();
         placed.insert(first);
         let score = self.get_highest_happiness_score_of_table(first, first, &mut placed);
-        score
+        Ok(score)
     }
 
-    fn solve_second_part(&self) -> isize {
+    fn solve_second_part(&self) -> Result<isize, SolveError> {
         let santa = "";
         let score = self.get_highest_happiness_score_of_table(santa, santa, &mut HashSet::new());
-        score
+        Ok(score)
     }
 }
 
@@ -102,7 +107,19 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_13::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 330);
+        let solver = Solver2015_13::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 330);
+    }
+
+    #[test]
+    fn should_reject_empty_input() {
+        assert_eq!(
+            Solver2015_13::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a happiness change, found end of input"
+            ))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2015_14 {
     duration: usize,
@@ -7,34 +7,31 @@ pub struct Solver2015_14 {
 
 impl Default for Solver2015_14 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_14 {
-    fn from(input: &str) -> Self {
-        let re_duration = regex::Regex::new(r"Duration: (?P<duration>\d+) seconds").unwrap();
-        let re_reindeer = regex::Regex::new(r"(?P<name>\w+) can fly (?P<speed>\d+) km/s for (?P<duration>\d+) seconds, but then must rest for (?P<rest>\d+) seconds.").unwrap();
-        Self {
-            duration: re_duration
-                .captures(input)
-                .unwrap()
-                .name("duration")
-                .unwrap()
-                .as_str()
-                .parse()
-                .unwrap(),
-            reindeer: re_reindeer
-                .captures_iter(input)
-                .map(|captures: regex::Captures| {
-                    (
-                        captures.name("speed").unwrap().as_str().parse().unwrap(),
-                        captures.name("duration").unwrap().as_str().parse().unwrap(),
-                        captures.name("rest").unwrap().as_str().parse().unwrap(),
-                    )
+impl TryFrom<&str> for Solver2015_14 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re_duration = regex::Regex::new(r"^Duration: (?P<duration>\d+) seconds$").unwrap();
+        let re_reindeer = regex::Regex::new(r"^(?P<name>\w+) can fly (?P<speed>\d+) km/s for (?P<duration>\d+) seconds, but then must rest for (?P<rest>\d+) seconds.$").unwrap();
+        let (duration, reindeer) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a list of reindeer"))?;
+        let duration = parse::captures(input, &re_duration, duration)?;
+        Ok(Self {
+            duration: parse::value(input, duration.name("duration").unwrap().as_str())?,
+            reindeer: reindeer
+                .lines()
+                .map(|line| {
+                    let captures = parse::captures(input, &re_reindeer, line)?;
+                    let value = |name| parse::value(input, captures.name(name).unwrap().as_str());
+                    Ok((value("speed")?, value("duration")?, value("rest")?))
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
@@ -76,18 +73,19 @@ impl Solver2015_14 {
 }
 
 impl Solver<usize, usize> for Solver2015_14 {
-    fn solve_first_part(&self) -> usize {
-        self.reindeer
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .reindeer
             .iter()
             .map(|(speed, move_duration, rest_duration)| {
                 get_total_distance(&self.duration, speed, move_duration, rest_duration)
             })
             .max()
-            .unwrap()
+            .unwrap())
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.get_winner_score()
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self.get_winner_score())
     }
 }
 
@@ -99,13 +97,13 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_14::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 1120);
+        let solver = Solver2015_14::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 1120);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2015_14::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 689);
+        let solver = Solver2015_14::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 689);
     }
 }
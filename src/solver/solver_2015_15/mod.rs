@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2015_15 {
     ingredients: Vec<(isize, isize, isize, isize, isize)>,
@@ -6,32 +6,31 @@ pub struct Solver2015_15 {
 
 impl Default for Solver2015_15 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_15 {
-    fn from(input: &str) -> Self {
-        let re = regex::Regex::new(r"(?P<name>\w+): capacity (?P<capacity>-?\d+), durability (?P<durability>-?\d+), flavor (?P<flavor>-?\d+), texture (?P<texture>-?\d+), calories (?P<calories>-?\d+)").unwrap();
-        Self {
-            ingredients: re
-                .captures_iter(input)
-                .map(|captures: regex::Captures| {
-                    (
-                        captures.name("capacity").unwrap().as_str().parse().unwrap(),
-                        captures
-                            .name("durability")
-                            .unwrap()
-                            .as_str()
-                            .parse()
-                            .unwrap(),
-                        captures.name("flavor").unwrap().as_str().parse().unwrap(),
-                        captures.name("texture").unwrap().as_str().parse().unwrap(),
-                        captures.name("calories").unwrap().as_str().parse().unwrap(),
-                    )
+impl TryFrom<&str> for Solver2015_15 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re = regex::Regex::new(r"^(?P<name>\w+): capacity (?P<capacity>-?\d+), durability (?P<durability>-?\d+), flavor (?P<flavor>-?\d+), texture (?P<texture>-?\d+), calories (?P<calories>-?\d+)$").unwrap();
+        Ok(Self {
+            ingredients: input
+                .lines()
+                .map(|line| {
+                    let captures = parse::captures(input, &re, line)?;
+                    let value = |name| parse::value(input, captures.name(name).unwrap().as_str());
+                    Ok((
+                        value("capacity")?,
+                        value("durability")?,
+                        value("flavor")?,
+                        value("texture")?,
+                        value("calories")?,
+                    ))
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
@@ -95,12 +94,12 @@ impl Solver2015_15 {
 }
 
 impl Solver<isize, isize> for Solver2015_15 {
-    fn solve_first_part(&self) -> isize {
-        self.get_best_score(false)
+    fn solve_first_part(&self) -> Result<isize, SolveError> {
+        Ok(self.get_best_score(false))
     }
 
-    fn solve_second_part(&self) -> isize {
-        self.get_best_score(true)
+    fn solve_second_part(&self) -> Result<isize, SolveError> {
+        Ok(self.get_best_score(true))
     }
 }
 
@@ -112,13 +111,13 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_15::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 62842880);
+        let solver = Solver2015_15::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 62842880);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2015_15::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 57600000);
+        let solver = Solver2015_15::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 57600000);
     }
 }
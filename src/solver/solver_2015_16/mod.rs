@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::HashMap;
 
 pub struct Solver2015_16<'a> {
@@ -8,44 +8,41 @@ pub struct Solver2015_16<'a> {
 
 impl Default for Solver2015_16<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_16<'a> {
-    fn from(input: &'a str) -> Self {
+impl<'a> TryFrom<&'a str> for Solver2015_16<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
         // Sue 1: goldfish: 6, trees: 9, akitas: 0
 
-        let re = regex::Regex::new(r"Sue (?P<id>\d+):(?P<rest>.*)").unwrap();
-        Self {
-            gifts: re
-                .captures_iter(input)
-                .map(|captures: regex::Captures| {
-                    (
-                        captures.name("id").unwrap().as_str().parse().unwrap(),
+        let re = regex::Regex::new(r"^Sue (?P<id>\d+): (?P<rest>.*)$").unwrap();
+        let re_compound = regex::Regex::new(r"^(?P<name>\w+): (?P<count>\d+)$").unwrap();
+        Ok(Self {
+            gifts: input
+                .lines()
+                .map(|line| {
+                    let captures = parse::captures(input, &re, line)?;
+                    Ok((
+                        parse::value(input, captures.name("id").unwrap().as_str())?,
                         captures
                             .name("rest")
                             .unwrap()
                             .as_str()
                             .split(", ")
                             .map(|s| {
-                                let re =
-                                    regex::Regex::new(r"(?P<name>\w+): (?P<count>\d+)").unwrap();
-                                (
-                                    re.captures(s).unwrap().name("name").unwrap().as_str(),
-                                    re.captures(s)
-                                        .unwrap()
-                                        .name("count")
-                                        .unwrap()
-                                        .as_str()
-                                        .parse()
-                                        .unwrap(),
-                                )
+                                let captures = parse::captures(input, &re_compound, s)?;
+                                Ok((
+                                    captures.name("name").unwrap().as_str(),
+                                    parse::value(input, captures.name("count").unwrap().as_str())?,
+                                ))
                             })
-                            .collect(),
-                    )
+                            .collect::<Result<_, ParseError>>()?,
+                    ))
                 })
-                .collect(),
+                .collect::<Result<_, ParseError>>()?,
             goal: HashMap::from([
                 ("children", 3),
                 ("cats", 7),
@@ -58,21 +55,21 @@ impl<'a> From<&'a str> for Solver2015_16<'a> {
                 ("cars", 2),
                 ("perfumes", 1),
             ]),
-        }
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2015_16<'_> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let sue = self.gifts.iter().find(|(_, gifts)| {
             gifts
                 .iter()
                 .all(|(name, count)| self.goal.get(name).unwrap() == count)
         });
-        sue.unwrap().0.clone()
+        sue.map(|(sue, _)| *sue).ok_or(SolveError::NoSolution)
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let sue = self.gifts.iter().find(|(_, gifts)| {
             gifts.iter().all(|(name, count)| {
                 let goal = self.goal.get(name).unwrap();
@@ -83,6 +80,6 @@ impl Solver<usize, usize> for Solver2015_16<'_> {
                 }
             })
         });
-        sue.unwrap().0.clone()
+        sue.map(|(sue, _)| *sue).ok_or(SolveError::NoSolution)
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2015_17 {
     total: usize,
@@ -7,17 +7,24 @@ pub struct Solver2015_17 {
 
 impl Default for Solver2015_17 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_17 {
-    fn from(input: &str) -> Self {
-        let parts = input.split("\n\n").collect::<Vec<_>>();
-        Self {
-            total: parts[0].parse().unwrap(),
-            containers: parts[1].lines().map(|line| line.parse().unwrap()).collect(),
-        }
+impl TryFrom<&str> for Solver2015_17 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (total, containers) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a list of containers"))?;
+        Ok(Self {
+            total: parse::value(input, total)?,
+            containers: containers
+                .lines()
+                .map(|line| parse::value(input, line))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -45,21 +52,21 @@ fn get_combinations(total: usize, containers: &[usize]) -> Vec<Vec<usize>> {
     result
 }
 impl Solver<usize, usize> for Solver2015_17 {
-    fn solve_first_part(&self) -> usize {
-        get_combinations(self.total, &self.containers).len()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(get_combinations(self.total, &self.containers).len())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let result = get_combinations(self.total, &self.containers);
         let min_length = result
             .iter()
             .map(|combination| combination.len())
             .min()
-            .unwrap();
-        result
+            .ok_or(SolveError::NoSolution)?;
+        Ok(result
             .iter()
             .filter(|combination| combination.len() == min_length)
-            .count()
+            .count())
     }
 }
 
@@ -71,13 +78,20 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_17::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 4);
+        let solver = Solver2015_17::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 4);
+    }
+
+    #[test]
+    fn should_fail_without_combination() {
+        let solver = Solver2015_17::try_from("25\n\n30\n20").unwrap();
+        assert_eq!(solver.solve_first_part(), Ok(0));
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2015_17::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 0);
+        let solver = Solver2015_17::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 0);
     }
 }
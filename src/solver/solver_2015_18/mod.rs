@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2015_18 {
     number_of_steps: usize,
@@ -7,19 +7,33 @@ pub struct Solver2015_18 {
 
 impl Default for Solver2015_18 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2015_18 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2015_18 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "#.\n")?;
+        let width = input.lines().next().map_or(0, str::len);
+        if width == 0 {
+            return Err(ParseError::end_of_input(input, "a grid of lights"));
+        }
+        if let Some(line) = input.lines().find(|line| line.len() != width) {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected {width} lights"),
+            ));
+        }
+        Ok(Self {
             number_of_steps: 100,
             lights: input
                 .lines()
                 .map(|line| line.chars().map(|c| c == '#').collect())
                 .collect(),
-        }
+        })
     }
 }
 
@@ -73,18 +87,18 @@ fn process_n_steps(lights: &Vec<Vec<bool>>, n: usize, keep_corners_open: bool) -
 }
 
 impl Solver<usize, usize> for Solver2015_18 {
-    fn solve_first_part(&self) -> usize {
-        process_n_steps(&self.lights, self.number_of_steps, false)
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(process_n_steps(&self.lights, self.number_of_steps, false)
             .iter()
             .map(|row| row.iter().filter(|light| **light).count())
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> usize {
-        process_n_steps(&self.lights, self.number_of_steps, true)
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(process_n_steps(&self.lights, self.number_of_steps, true)
             .iter()
             .map(|row| row.iter().filter(|light| **light).count())
-            .sum()
+            .sum())
     }
 }
 
@@ -96,15 +110,31 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let mut solver = Solver2015_18::from(EXAMPLE);
+        let mut solver = Solver2015_18::try_from(EXAMPLE).unwrap();
         solver.number_of_steps = 4;
-        assert_eq!(solver.solve_first_part(), 4);
+        assert_eq!(solver.solve_first_part().unwrap(), 4);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let mut solver = Solver2015_18::from(EXAMPLE);
+        let mut solver = Solver2015_18::try_from(EXAMPLE).unwrap();
         solver.number_of_steps = 5;
-        assert_eq!(solver.solve_second_part(), 17);
+        assert_eq!(solver.solve_second_part().unwrap(), 17);
+    }
+
+    #[test]
+    fn should_reject_uneven_rows() {
+        assert_eq!(
+            Solver2015_18::try_from("#.\n#").err(),
+            Some(ParseError::new(2, 1, "expected 2 lights"))
+        );
+        assert_eq!(
+            Solver2015_18::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a grid of lights, found end of input"
+            ))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::collections::{HashMap, HashSet};
 
 pub struct Solver2015_19<'a> {
@@ -8,30 +8,33 @@ pub struct Solver2015_19<'a> {
 
 impl Default for Solver2015_19<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2015_19<'a> {
-    fn from(input: &'a str) -> Self {
-        let mut parts = input.split("\n\n");
+impl<'a> TryFrom<&'a str> for Solver2015_19<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let (rules, molecule) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a molecule"))?;
         let mut replacements: HashMap<&'a str, Vec<&'a str>> = HashMap::new();
-        for line in parts.next().unwrap().lines() {
-            let mut parts = line.split(" => ");
-            replacements
-                .entry(parts.next().unwrap())
-                .or_default()
-                .push(parts.next().unwrap());
+        for line in rules.lines() {
+            let (from, to) = line
+                .split_once(" => ")
+                .ok_or_else(|| ParseError::at(input, line, "expected `FROM => TO`"))?;
+            replacements.entry(from).or_default().push(to);
         }
-        Self {
+        Ok(Self {
             replacements,
-            input: parts.next().unwrap(),
-        }
+            input: molecule,
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2015_19<'_> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut outputs = HashSet::new();
         for (old_string, new_strings) in &self.replacements {
             for (i, _) in self.input.match_indices(old_string) {
@@ -42,10 +45,10 @@ impl Solver<usize, usize> for Solver2015_19<'_> {
                 }
             }
         }
-        outputs.len()
+        Ok(outputs.len())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         // Thanks to reddit user /u/askalski
         // https://www.reddit.com/r/adventofcode/comments/3xflz8/day_19_solutions/cy4etju/
         let element_count = self
@@ -56,7 +59,7 @@ impl Solver<usize, usize> for Solver2015_19<'_> {
         let rn_count = self.input.matches("Rn").count();
         let ar_count = self.input.matches("Ar").count();
         let y_count = self.input.matches("Y").count();
-        element_count - rn_count - ar_count - (2 * y_count) - 1
+        Ok(element_count - rn_count - ar_count - (2 * y_count) - 1)
     }
 }
 
@@ -68,7 +71,7 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2015_19::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 4);
+        let solver = Solver2015_19::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 4);
     }
 }
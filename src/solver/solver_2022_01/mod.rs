@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2022_01 {
     carried_foods: Vec<i32>,
@@ -6,26 +6,36 @@ pub struct Solver2022_01 {
 
 impl Default for Solver2022_01 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl From<&str> for Solver2022_01 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2022_01 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
             carried_foods: input
                 .split("\n\n")
-                .map(|e| e.lines().map(|i| i.parse::<i32>().unwrap()).sum())
-                .collect(),
-        }
+                .map(|e| {
+                    e.lines()
+                        .map(|i| parse::value::<i32>(input, i))
+                        .sum::<Result<_, _>>()
+                })
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
 impl Solver<i32, i32> for Solver2022_01 {
-    fn solve_first_part(&self) -> i32 {
-        self.carried_foods.iter().max().unwrap().clone()
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        self.carried_foods
+            .iter()
+            .max()
+            .copied()
+            .ok_or(SolveError::NoSolution)
     }
 
-    fn solve_second_part(&self) -> i32 {
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
         let mut result = vec![0; 3];
         for &number in &self.carried_foods {
             if number > result[0] {
@@ -39,7 +49,7 @@ impl Solver<i32, i32> for Solver2022_01 {
                 result[2] = number;
             }
         }
-        result.iter().sum()
+        Ok(result.iter().sum())
     }
 }
 
@@ -49,12 +59,12 @@ mod tests {
     const EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn solve_first_part() {
-        let solver = Solver2022_01::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 24000);
+        let solver = Solver2022_01::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 24000);
     }
     #[test]
     fn solve_second_part() {
-        let solver = Solver2022_01::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 45000);
+        let solver = Solver2022_01::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 45000);
     }
 }
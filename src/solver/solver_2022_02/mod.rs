@@ -1,6 +1,7 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
+use regex::Regex;
 
-enum Result {
+enum Outcome {
     Win,
     Draw,
     Lose,
@@ -23,25 +24,25 @@ impl Round {
         Round { player, opponent }
     }
 
-    fn get_result(&self) -> Result {
+    fn get_result(&self) -> Outcome {
         match (&self.player, &self.opponent) {
             (Choice::Rock, Choice::Scissors)
             | (Choice::Paper, Choice::Rock)
-            | (Choice::Scissors, Choice::Paper) => Result::Win,
+            | (Choice::Scissors, Choice::Paper) => Outcome::Win,
             (Choice::Rock, Choice::Paper)
             | (Choice::Paper, Choice::Scissors)
-            | (Choice::Scissors, Choice::Rock) => Result::Lose,
+            | (Choice::Scissors, Choice::Rock) => Outcome::Lose,
             (Choice::Rock, Choice::Rock)
             | (Choice::Paper, Choice::Paper)
-            | (Choice::Scissors, Choice::Scissors) => Result::Draw,
+            | (Choice::Scissors, Choice::Scissors) => Outcome::Draw,
         }
     }
 
     fn get_score(&self) -> i32 {
         let score_from_result = match self.get_result() {
-            Result::Win => 6,
-            Result::Draw => 3,
-            Result::Lose => 0,
+            Outcome::Win => 6,
+            Outcome::Draw => 3,
+            Outcome::Lose => 0,
         };
         let score_from_choice = match self.player {
             Choice::Rock => 1,
@@ -58,29 +59,34 @@ pub struct Solver2022_02 {
 
 impl Default for Solver2022_02 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_02 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2022_02 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re = Regex::new(r"^[ABC] [XYZ]$").unwrap();
         let rounds = input
             .lines()
             .map(|line| {
+                parse::captures(input, &re, line)?;
                 let choices: Vec<&str> = line.split(" ").collect();
-                (
+                Ok((
                     choices[1].chars().nth(0).unwrap(),
                     choices[0].chars().nth(0).unwrap(),
-                )
+                ))
             })
-            .collect();
-        Self { rounds }
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { rounds })
     }
 }
 
 impl Solver<i32, i32> for Solver2022_02 {
-    fn solve_first_part(&self) -> i32 {
-        self.rounds
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .rounds
             .iter()
             .map(|(player, opponent)| {
                 let player = match player {
@@ -97,11 +103,12 @@ impl Solver<i32, i32> for Solver2022_02 {
                 };
                 Round::new(player, opponent).get_score()
             })
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> i32 {
-        self.rounds
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .rounds
             .iter()
             .map(|(player, opponent)| {
                 let opponent = match opponent {
@@ -124,7 +131,7 @@ impl Solver<i32, i32> for Solver2022_02 {
                 };
                 Round::new(player, opponent).get_score()
             })
-            .sum()
+            .sum())
     }
 }
 #[cfg(test)]
@@ -133,12 +140,12 @@ mod tests {
     const EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn solve_first_part() {
-        let solver = Solver2022_02::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 15);
+        let solver = Solver2022_02::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 15);
     }
     #[test]
     fn solve_second_part() {
-        let solver = Solver2022_02::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 12);
+        let solver = Solver2022_02::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 12);
     }
 }
@@ -1,21 +1,16 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::borrow::Cow;
 use std::collections::HashSet;
 
-fn find_common_character(a: &str, b: &str) -> char {
+fn find_common_character(a: &str, b: &str) -> Option<char> {
     let mut map: HashSet<char> = HashSet::new();
     for char in a.chars() {
         map.insert(char);
     }
-    for char in b.chars() {
-        if map.contains(&char) {
-            return char;
-        }
-    }
-    panic!("common not found in {} and {}", a, b);
+    b.chars().find(|char| map.contains(char))
 }
 
-fn find_common_character_in_three_strings(a: &str, b: &str, c: &str) -> char {
+fn find_common_character_in_three_strings(a: &str, b: &str, c: &str) -> Option<char> {
     let mut map: HashSet<char> = HashSet::new();
     for char in a.chars() {
         map.insert(char);
@@ -26,12 +21,7 @@ fn find_common_character_in_three_strings(a: &str, b: &str, c: &str) -> char {
             second_map.insert(char);
         }
     }
-    for char in c.chars() {
-        if second_map.contains(&char) {
-            return char;
-        }
-    }
-    panic!("common not found in {} and {}", a, b);
+    c.chars().find(|char| second_map.contains(char))
 }
 
 fn character_to_score(c: char) -> u32 {
@@ -50,33 +40,56 @@ pub struct Solver2022_03<'a> {
 
 impl<'a> Default for Solver2022_03<'a> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_03<'a> {
-    fn from(input: &'a str) -> Self {
-        Self {
-            lines: input.lines().map(|line| Cow::Borrowed(line)).collect(),
+impl<'a> TryFrom<&'a str> for Solver2022_03<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(
+            input,
+            input,
+            "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ\n",
+        )?;
+        if let Some(line) = input.lines().find(|line| line.len() % 2 != 0) {
+            return Err(ParseError::at(
+                input,
+                line,
+                "expected the same number of items in both compartments",
+            ));
         }
+        if input.is_empty() {
+            return Err(ParseError::end_of_input(input, "a rucksack"));
+        }
+        Ok(Self {
+            lines: input.lines().map(|line| Cow::Borrowed(line)).collect(),
+        })
     }
 }
 
 impl<'a> Solver<u32, u32> for Solver2022_03<'a> {
-    fn solve_first_part(&self) -> u32 {
+    fn solve_first_part(&self) -> Result<u32, SolveError> {
         self.lines
             .iter()
             .map(|line| {
                 let (first, second) = line.split_at(line.len() / 2);
-                let common = find_common_character(first, second);
-                character_to_score(common)
+                let common = find_common_character(first, second).ok_or(SolveError::NoSolution)?;
+                Ok(character_to_score(common))
             })
-            .sum::<u32>()
+            .sum()
     }
 
-    fn solve_second_part(&self) -> u32 {
+    fn solve_second_part(&self) -> Result<u32, SolveError> {
         let mut result: u32 = 0;
 
+        if self.lines.chunks(3).any(|chunk| chunk.len() != 3) {
+            return Err(SolveError::Unsupported(format!(
+                "{} rucksacks don't make groups of three elves",
+                self.lines.len()
+            )));
+        }
         for chunk in self.lines.chunks(3) {
             let first_line = chunk[0].as_ref();
             let second_line = chunk[1].as_ref();
@@ -85,11 +98,12 @@ impl<'a> Solver<u32, u32> for Solver2022_03<'a> {
                 first_line.as_ref(),
                 second_line.as_ref(),
                 third_line.as_ref(),
-            );
+            )
+            .ok_or(SolveError::NoSolution)?;
             result += character_to_score(common);
         }
 
-        result
+        Ok(result)
     }
 }
 
@@ -99,12 +113,50 @@ mod tests {
     const EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn solve_first_part() {
-        let solver = Solver2022_03::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 157);
+        let solver = Solver2022_03::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 157);
     }
     #[test]
     fn solve_second_part() {
-        let solver = Solver2022_03::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 70);
+        let solver = Solver2022_03::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 70);
+    }
+    #[test]
+    fn fail_without_common_item() {
+        let solver = Solver2022_03::try_from("abcd\nefgh\nijkl").unwrap();
+        assert_eq!(solver.solve_first_part(), Err(SolveError::NoSolution));
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
+    }
+
+    #[test]
+    fn reject_incomplete_rucksacks() {
+        assert_eq!(
+            Solver2022_03::try_from("x").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected the same number of items in both compartments"
+            ))
+        );
+        assert_eq!(
+            Solver2022_03::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a rucksack, found end of input"
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_second_part_without_complete_groups() {
+        let solver = Solver2022_03::try_from("aa\nbb").unwrap();
+        assert_eq!(solver.solve_first_part(), Ok(3));
+        assert_eq!(
+            solver.solve_second_part(),
+            Err(SolveError::Unsupported(
+                "2 rucksacks don't make groups of three elves".to_string()
+            ))
+        );
     }
 }
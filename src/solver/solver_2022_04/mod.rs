@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 fn does_contain(bounds: &(i32, i32), other: &(i32, i32)) -> bool {
     bounds.0 <= other.0 && bounds.1 >= other.1
@@ -15,47 +15,50 @@ pub struct Solver2022_04 {
 
 impl Default for Solver2022_04 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl From<&str> for Solver2022_04 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2022_04 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
             assignments: input
                 .split("\n")
                 .map(|row| {
-                    let assignments: Vec<(i32, i32)> = row
-                        .split(",")
-                        .map(|assignment| {
-                            let bounds: Vec<i32> = assignment
-                                .split("-")
-                                .map(|bound| bound.parse().unwrap())
-                                .collect();
-                            (bounds[0], bounds[1])
-                        })
-                        .collect();
-                    (assignments[0], assignments[1])
+                    let (first, second) = row
+                        .split_once(",")
+                        .ok_or_else(|| ParseError::at(input, row, "expected two assignments"))?;
+                    let assignment = |assignment: &str| -> Result<_, ParseError> {
+                        let (start, end) = assignment
+                            .split_once("-")
+                            .ok_or_else(|| ParseError::at(input, assignment, "expected a range"))?;
+                        Ok((parse::value(input, start)?, parse::value(input, end)?))
+                    };
+                    Ok((assignment(first)?, assignment(second)?))
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2022_04 {
-    fn solve_first_part(&self) -> usize {
-        self.assignments
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .assignments
             .iter()
             .filter(|(first_bound, second_bound)| {
                 does_contain(first_bound, second_bound) || does_contain(second_bound, first_bound)
             })
-            .count()
+            .count())
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.assignments
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .assignments
             .iter()
             .filter(|(a, b)| does_overlap(a, b))
-            .count()
+            .count())
     }
 }
 
@@ -65,12 +68,12 @@ mod tests {
     static EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn solve_first_part() {
-        let solver = Solver2022_04::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 2);
+        let solver = Solver2022_04::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2);
     }
     #[test]
     fn solve_second_part() {
-        let solver = Solver2022_04::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 4);
+        let solver = Solver2022_04::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 4);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
 
 #[derive(Clone)]
@@ -9,48 +9,66 @@ pub struct Solver2022_05 {
 
 impl Default for Solver2022_05 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
-impl From<&str> for Solver2022_05 {
-    fn from(input: &str) -> Self {
-        let parts: Vec<&str> = input.split("\n\n").collect();
-        let mut stack_drawing: Vec<&str> = parts[0].split("\n").collect();
-        let number_of_stacks: usize = stack_drawing
-            .pop()
-            .unwrap()
-            .split(" ")
-            .collect::<Vec<&str>>()
-            .pop()
-            .unwrap()
-            .parse()
-            .unwrap();
+impl TryFrom<&str> for Solver2022_05 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (drawing, procedures) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a rearrangement procedure"))?;
+        let mut stack_drawing: Vec<&str> = drawing.split("\n").collect();
+        let number_of_stacks: usize = parse::value(
+            input,
+            stack_drawing.pop().unwrap().split(" ").last().unwrap(),
+        )?;
         let mut stacks: Vec<Vec<char>> = vec![Vec::new(); number_of_stacks];
         for line in stack_drawing.iter().rev() {
             let chars: Vec<char> = line.chars().collect();
             for i in 0..(chars.len() + 1) / 4 {
                 let item = chars[i * 4 + 1];
                 if item != ' ' {
-                    stacks[i].push(chars[i * 4 + 1]);
+                    stacks
+                        .get_mut(i)
+                        .ok_or_else(|| ParseError::at(input, line, "too many stacks"))?
+                        .push(chars[i * 4 + 1]);
                 }
             }
         }
-        let re = Regex::new(r"move (\d+) from (\d+) to (\d+)").unwrap();
-        let procedures = re
-            .captures_iter(parts[1])
-            .map(|capture| {
-                let count: usize = (&capture[1]).parse().unwrap();
-                let from: usize = (&capture[2]).parse::<usize>().unwrap() - 1;
-                let to: usize = (&capture[3]).parse::<usize>().unwrap() - 1;
-                (count, from, to)
+        let re = Regex::new(r"^move (\d+) from (\d+) to (\d+)$").unwrap();
+        let stack = |span: &str| match parse::value::<usize>(input, span)? {
+            index @ 1.. if index <= number_of_stacks => Ok(index - 1),
+            _ => Err(ParseError::at(input, span, "no such stack")),
+        };
+        // The crane can't lift more crates than a stack holds, so the heights are followed here.
+        let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+        let procedures = procedures
+            .lines()
+            .map(|line| {
+                let capture = parse::captures(input, &re, line)?;
+                let count: usize = parse::value(input, &capture[1])?;
+                let from = stack(&capture[2])?;
+                let to = stack(&capture[3])?;
+                if count > heights[from] {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("stack {} only holds {} crates", from + 1, heights[from]),
+                    ));
+                }
+                heights[from] -= count;
+                heights[to] += count;
+                Ok((count, from, to))
             })
-            .collect();
-        Self { stacks, procedures }
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { stacks, procedures })
     }
 }
 
 impl Solver<String, String> for Solver2022_05 {
-    fn solve_first_part(&self) -> String {
+    fn solve_first_part(&self) -> Result<String, SolveError> {
         let mut mutated = self.clone();
         for (count, from, to) in mutated.procedures.iter() {
             let new_length = mutated.stacks[*from].len() - *count;
@@ -61,11 +79,11 @@ impl Solver<String, String> for Solver2022_05 {
         mutated
             .stacks
             .iter_mut()
-            .map(|stack| stack.pop().unwrap())
+            .map(|stack| stack.pop().ok_or(SolveError::NoSolution))
             .collect()
     }
 
-    fn solve_second_part(&self) -> String {
+    fn solve_second_part(&self) -> Result<String, SolveError> {
         let mut mutated = self.clone();
         for (count, from, to) in mutated.procedures.iter() {
             let new_length = mutated.stacks[*from].len() - *count;
@@ -76,7 +94,7 @@ impl Solver<String, String> for Solver2022_05 {
         mutated
             .stacks
             .iter_mut()
-            .map(|stack| stack.pop().unwrap())
+            .map(|stack| stack.pop().ok_or(SolveError::NoSolution))
             .collect()
     }
 }
@@ -88,12 +106,27 @@ mod tests {
     static EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn solve_first_part() {
-        let solver = Solver2022_05::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), "CMZ");
+        let solver = Solver2022_05::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), "CMZ");
     }
     #[test]
     fn solve_second_part() {
-        let solver = Solver2022_05::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), "MCD");
+        let solver = Solver2022_05::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), "MCD");
+    }
+    #[test]
+    fn fail_with_empty_stack() {
+        let input = EXAMPLE.replace("\nmove 1 from 1 to 2", "");
+        let solver = Solver2022_05::try_from(input.as_str()).unwrap();
+        assert_eq!(solver.solve_first_part(), Err(SolveError::NoSolution));
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
+    }
+    #[test]
+    fn reject_move_of_missing_crates() {
+        assert_eq!(
+            Solver2022_05::try_from(EXAMPLE.replace("move 2 from 2", "move 3 from 2").as_str())
+                .err(),
+            Some(ParseError::new(8, 1, "stack 2 only holds 2 crates"))
+        );
     }
 }
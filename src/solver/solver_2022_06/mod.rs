@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::borrow::Cow;
 use std::collections::HashSet;
 
@@ -8,20 +8,22 @@ pub struct Solver2022_06<'a> {
 
 impl<'a> Default for Solver2022_06<'a> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_06<'a> {
-    fn from(input: &'a str) -> Self {
-        Self {
+impl<'a> TryFrom<&'a str> for Solver2022_06<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
             input: Cow::Borrowed(input),
-        }
+        })
     }
 }
 
 impl<'a> Solver2022_06<'a> {
-    fn find_start_of_message_marker(&self, message_length: usize) -> usize {
+    fn find_start_of_message_marker(&self, message_length: usize) -> Result<usize, SolveError> {
         let chars: Vec<char> = self.input.chars().collect();
         for i in message_length - 1..chars.len() {
             let mut set = HashSet::<char>::new();
@@ -29,19 +31,19 @@ impl<'a> Solver2022_06<'a> {
                 set.insert(chars[i - j]);
             }
             if set.len() == message_length {
-                return i + 1;
+                return Ok(i + 1);
             }
         }
-        panic!("couldn't solve it.");
+        Err(SolveError::NoSolution)
     }
 }
 
 impl<'a> Solver<usize, usize> for Solver2022_06<'a> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         self.find_start_of_message_marker(4)
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         self.find_start_of_message_marker(14)
     }
 }
@@ -52,52 +54,60 @@ mod find_start_of_message_marker {
 
     #[test]
     fn first_example() {
-        let solver = super::Solver2022_06::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
-        assert_eq!(solver.solve_first_part(), 7);
+        let solver = super::Solver2022_06::try_from("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 7);
     }
     #[test]
     fn second_example() {
-        let solver = super::Solver2022_06::from("bvwbjplbgvbhsrlpgdmjqwftvncz");
-        assert_eq!(solver.solve_first_part(), 5);
+        let solver = super::Solver2022_06::try_from("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 5);
     }
     #[test]
     fn third_example() {
-        let solver = super::Solver2022_06::from("nppdvjthqldpwncqszvftbrmjlhg");
-        assert_eq!(solver.solve_first_part(), 6);
+        let solver = super::Solver2022_06::try_from("nppdvjthqldpwncqszvftbrmjlhg").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 6);
     }
     #[test]
     fn fourth_example() {
-        let solver = super::Solver2022_06::from("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg");
-        assert_eq!(solver.solve_first_part(), 10);
+        let solver = super::Solver2022_06::try_from("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 10);
     }
     #[test]
     fn fifth_example() {
-        let solver = super::Solver2022_06::from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
-        assert_eq!(solver.solve_first_part(), 11);
+        let solver = super::Solver2022_06::try_from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 11);
     }
     #[test]
     fn first_example_in_second_part() {
-        let solver = super::Solver2022_06::from("mjqjpqmgbljsphdztnvjfqwrcgsmlb");
-        assert_eq!(solver.solve_second_part(), 19);
+        let solver = super::Solver2022_06::try_from("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 19);
     }
     #[test]
     fn second_example_in_second_part() {
-        let solver = super::Solver2022_06::from("bvwbjplbgvbhsrlpgdmjqwftvncz");
-        assert_eq!(solver.solve_second_part(), 23);
+        let solver = super::Solver2022_06::try_from("bvwbjplbgvbhsrlpgdmjqwftvncz").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 23);
     }
     #[test]
     fn third_example_in_second_part() {
-        let solver = super::Solver2022_06::from("nppdvjthqldpwncqszvftbrmjlhg");
-        assert_eq!(solver.solve_second_part(), 23);
+        let solver = super::Solver2022_06::try_from("nppdvjthqldpwncqszvftbrmjlhg").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 23);
     }
     #[test]
     fn fourth_example_in_second_part() {
-        let solver = super::Solver2022_06::from("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg");
-        assert_eq!(solver.solve_second_part(), 29);
+        let solver = super::Solver2022_06::try_from("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 29);
     }
     #[test]
     fn fifth_example_in_second_part() {
-        let solver = super::Solver2022_06::from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw");
-        assert_eq!(solver.solve_second_part(), 26);
+        let solver = super::Solver2022_06::try_from("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 26);
+    }
+    #[test]
+    fn no_marker() {
+        let solver = super::Solver2022_06::try_from("abcabcabc").unwrap();
+        assert_eq!(
+            solver.solve_first_part(),
+            Err(super::SolveError::NoSolution)
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use pest::iterators::Pair;
 use pest::Parser;
 use std::collections::{HashMap, HashSet};
@@ -54,16 +54,21 @@ pub struct Solver2022_07 {
 
 impl Default for Solver2022_07 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_07 {
-    fn from(input: &str) -> Self {
-        let pairs = SantaParser::parse(Rule::Program, input).unwrap_or_else(|e| panic!("{}", e));
+impl TryFrom<&str> for Solver2022_07 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let pairs = SantaParser::parse(Rule::Program, input)?;
         let program = pairs.peek().unwrap();
         let mut current_directory = Vec::<String>::new();
         let mut folder_map = HashMap::<String, Folder>::new();
+        folder_map.insert("/".to_string(), Folder::from(&current_directory));
+        // The folders seen in listings, which have to be listed themselves for their size.
+        let mut listed_folders = Vec::<(&str, String)>::new();
         for statement in program.into_inner() {
             match statement.as_rule() {
                 Rule::CDCommand => {
@@ -90,19 +95,17 @@ impl From<&str> for Solver2022_07 {
                     for pair in pairs {
                         match pair.as_rule() {
                             Rule::DirectoryInfo => {
-                                current_directory
-                                    .push(pair.into_inner().peek().unwrap().as_str().to_string());
-                                sub_folders.insert(Folder::generate_path(&current_directory));
+                                let name = pair.into_inner().peek().unwrap().as_str();
+                                current_directory.push(name.to_string());
+                                let path = Folder::generate_path(&current_directory);
+                                sub_folders.insert(path.clone());
+                                listed_folders.push((name, path));
                                 current_directory.pop();
                             }
                             Rule::FileInfo => {
-                                file_size += pair
-                                    .into_inner()
-                                    .peek()
-                                    .unwrap()
-                                    .as_str()
-                                    .parse::<u32>()
-                                    .unwrap();
+                                file_size += parse::pair_value::<u32, _>(
+                                    &pair.into_inner().peek().unwrap(),
+                                )?;
                             }
                             other => panic!("syntax error: ls command cannot have {:?}", other),
                         }
@@ -113,9 +116,20 @@ impl From<&str> for Solver2022_07 {
                         f.file_size = file_size;
                     });
                 }
+                Rule::EOI => {}
                 other => panic!("syntax error: statement cannot be {:?}", other),
             }
         }
+        if let Some((name, path)) = listed_folders
+            .iter()
+            .find(|(_, path)| !folder_map.contains_key(path))
+        {
+            return Err(ParseError::at(
+                input,
+                name,
+                format!("the folder `{path}` is never visited"),
+            ));
+        }
         let mut size_map: HashMap<String, u32> = HashMap::new();
         while size_map.len() < folder_map.len() {
             for folder in folder_map.values() {
@@ -130,29 +144,34 @@ impl From<&str> for Solver2022_07 {
                 }
             }
         }
-        Self { size_map }
+        Ok(Self { size_map })
     }
 }
 
 impl Solver<u32, u32> for Solver2022_07 {
-    fn solve_first_part(&self) -> u32 {
-        self.size_map.values().filter(|&&size| size < 100000).sum()
+    fn solve_first_part(&self) -> Result<u32, SolveError> {
+        Ok(self.size_map.values().filter(|&&size| size < 100000).sum())
     }
 
-    fn solve_second_part(&self) -> u32 {
-        let available_size = 70000000;
-        let required_size = 30000000;
-        let used_space = self.size_map.get("/").unwrap();
-        let free_space = available_size - used_space;
-        let needed_space = required_size - free_space;
+    fn solve_second_part(&self) -> Result<u32, SolveError> {
+        let available_size: u32 = 70000000;
+        let required_size: u32 = 30000000;
+        let used_space = &self.size_map["/"];
+        let free_space = available_size.checked_sub(*used_space).ok_or_else(|| {
+            SolveError::Unsupported(format!(
+                "{used_space} is used on a disk of {available_size}"
+            ))
+        })?;
+        // With enough free space already, deleting any folder would do.
+        let needed_space = required_size.saturating_sub(free_space);
 
-        self.size_map.values().fold(*used_space, |candidate, size| {
+        Ok(self.size_map.values().fold(*used_space, |candidate, size| {
             if *size > needed_space && *size < candidate {
                 *size
             } else {
                 candidate
             }
-        })
+        }))
     }
 }
 
@@ -162,13 +181,32 @@ mod tests {
     static EXAMPLE: &str = "$ cd /\n$ ls\ndir a\n14848514 b.txt\n8504156 c.dat\ndir d\n$ cd a\n$ ls\ndir e\n29116 f\n2557 g\n62596 h.lst\n$ cd e\n$ ls\n584 i\n$ cd ..\n$ cd ..\n$ cd d\n$ ls\n4060174 j\n8033020 d.log\n5626152 d.ext\n7214296 k";
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_07::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 95437);
+        let solver = Solver2022_07::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 95437);
+    }
+
+    #[test]
+    fn should_reject_folder_that_is_never_visited() {
+        assert_eq!(
+            Solver2022_07::try_from("$ cd /\n$ ls\ndir a\n1 b").err(),
+            Some(ParseError::new(3, 5, "the folder `/a` is never visited"))
+        );
+    }
+
+    #[test]
+    fn should_fail_when_disk_overflows() {
+        let solver = Solver2022_07::try_from("$ cd /\n$ ls\n70000001 a").unwrap();
+        assert_eq!(
+            solver.solve_second_part(),
+            Err(SolveError::Unsupported(
+                "70000001 is used on a disk of 70000000".to_string()
+            ))
+        );
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_07::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 24933642);
+        let solver = Solver2022_07::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 24933642);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2022_08 {
     forest: Vec<Vec<u32>>,
@@ -6,23 +6,37 @@ pub struct Solver2022_08 {
 
 impl Default for Solver2022_08 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_08 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2022_08 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "0123456789\n")?;
+        let width = input.lines().next().map_or(0, str::len);
+        if width == 0 {
+            return Err(ParseError::end_of_input(input, "a tree"));
+        }
+        if let Some(row) = input.lines().find(|row| row.len() != width) {
+            return Err(ParseError::at(
+                input,
+                row,
+                format!("expected {width} trees"),
+            ));
+        }
+        Ok(Self {
             forest: input
                 .lines()
                 .map(|row| row.chars().map(|tree| tree.to_digit(10).unwrap()).collect())
                 .collect(),
-        }
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2022_08 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let row_length = self.forest.len();
         let column_length = self.forest[0].len();
         let mut visible_map = vec![vec![false; column_length]; row_length];
@@ -63,15 +77,15 @@ impl Solver<usize, usize> for Solver2022_08 {
                 }
             }
         }
-        visible_map.iter().fold(0, |count, row| {
+        Ok(visible_map.iter().fold(0, |count, row| {
             row.iter().fold(
                 count,
                 |count, is_visible| if *is_visible { count + 1 } else { count },
             )
-        })
+        }))
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let row_length = self.forest.len();
         let column_length = self.forest[0].len();
         let mut scene_map = vec![vec![[0usize; 4]; column_length]; row_length];
@@ -121,11 +135,11 @@ impl Solver<usize, usize> for Solver2022_08 {
             }
         }
 
-        scene_map.iter().fold(0, |highest_score, row| {
+        Ok(scene_map.iter().fold(0, |highest_score, row| {
             row.iter().fold(highest_score, |highest_score, item| {
                 std::cmp::max(item[0] * item[1] * item[2] * item[3], highest_score)
             })
-        })
+        }))
     }
 }
 
@@ -141,13 +155,25 @@ mod tests {
 ";
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_08::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 21);
+        let solver = Solver2022_08::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 21);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_08::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 8);
+        let solver = Solver2022_08::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 8);
+    }
+
+    #[test]
+    fn should_reject_empty_forest() {
+        assert_eq!(
+            Solver2022_08::try_from("").err(),
+            Some(ParseError::new(1, 1, "expected a tree, found end of input"))
+        );
+        assert_eq!(
+            Solver2022_08::try_from("12\n3").err(),
+            Some(ParseError::new(2, 1, "expected 2 trees"))
+        );
     }
 }
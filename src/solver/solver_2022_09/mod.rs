@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::cmp::Ordering;
 
 const BOARD_SIZE: usize = 1000;
@@ -120,30 +120,34 @@ pub struct Solver2022_09 {
 
 impl Default for Solver2022_09 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_09 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2022_09 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
             steps: input
                 .split("\n")
-                .map(|step| -> (Direction, usize) {
-                    let parts: Vec<&str> = step.split(" ").collect();
-                    (
-                        match parts[0] {
+                .map(|step| {
+                    let (direction, distance) = step.split_once(" ").ok_or_else(|| {
+                        ParseError::at(input, step, "expected a direction and a distance")
+                    })?;
+                    Ok((
+                        match direction {
                             "U" => Direction::Up,
                             "D" => Direction::Down,
                             "L" => Direction::Left,
                             "R" => Direction::Right,
-                            _ => unreachable!(),
+                            _ => return Err(ParseError::unexpected(input, direction)),
                         },
-                        parts[1].parse().unwrap(),
-                    )
+                        parse::value(input, distance)?,
+                    ))
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
@@ -170,12 +174,12 @@ impl Solver2022_09 {
 }
 
 impl Solver<usize, usize> for Solver2022_09 {
-    fn solve_first_part(&self) -> usize {
-        self.solve_part(2)
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self.solve_part(2))
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.solve_part(10)
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self.solve_part(10))
     }
 }
 
@@ -185,7 +189,7 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_09::from(
+        let solver = Solver2022_09::try_from(
             "\
 R 4
 U 4
@@ -196,13 +200,14 @@ D 1
 L 5
 R 2\
 ",
-        );
-        assert_eq!(solver.solve_first_part(), 13);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 13);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_09::from(
+        let solver = Solver2022_09::try_from(
             "\
 R 5
 U 8
@@ -213,7 +218,8 @@ D 10
 L 25
 U 20\
 ",
-        );
-        assert_eq!(solver.solve_second_part(), 36);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 36);
     }
 }
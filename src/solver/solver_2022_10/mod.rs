@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, Grid, ParseError, SolveError, Solver};
 use regex::Regex;
 
 #[derive(Clone)]
@@ -13,37 +13,41 @@ pub struct Solver2022_10 {
 
 impl Default for Solver2022_10 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_10 {
-    fn from(input: &str) -> Self {
-        let re = Regex::new(r"addx (-?\d+)").unwrap();
+impl TryFrom<&str> for Solver2022_10 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re = Regex::new(r"^addx (-?\d+)$").unwrap();
 
         let operations = input
             .split("\n")
             .map(|operation| match operation {
-                "noop" => Operation::NoOp,
-                other => Operation::Add(re.captures(other).unwrap()[1].parse().unwrap()),
+                "noop" => Ok(Operation::NoOp),
+                other => Ok(Operation::Add(parse::value(
+                    input,
+                    parse::captures(input, &re, other)?.get(1).unwrap().as_str(),
+                )?)),
             })
-            .collect();
-        Self { operations }
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { operations })
     }
 }
 
-fn get_pixel(cycle_count: i32, register: i32) -> String {
+fn get_pixel(cycle_count: i32, register: i32) -> char {
     let cycle_count = cycle_count % 40;
-    let suffix = if cycle_count == 39 { "\n" } else { "" };
     if cycle_count >= register && cycle_count <= register + 2 {
-        "#".to_owned() + suffix
+        '#'
     } else {
-        ".".to_owned() + suffix
+        '.'
     }
 }
 
-impl Solver<i32, String> for Solver2022_10 {
-    fn solve_first_part(&self) -> i32 {
+impl Solver<i32, Grid> for Solver2022_10 {
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
         let mut cycle_count = 0;
         let mut register = 1;
         let mut result = 0;
@@ -69,31 +73,31 @@ impl Solver<i32, String> for Solver2022_10 {
                 }
             }
         }
-        result
+        Ok(result)
     }
 
-    fn solve_second_part(&self) -> String {
+    fn solve_second_part(&self) -> Result<Grid, SolveError> {
         let mut cycle_count = 0;
         let mut register = 0;
-        let mut result = String::from("");
+        let mut pixels = vec![];
         for operation in &self.operations {
             match operation {
                 Operation::NoOp => {
-                    result += get_pixel(cycle_count, register).as_str();
+                    pixels.push(get_pixel(cycle_count, register));
                     cycle_count += 1;
                 }
                 Operation::Add(value) => {
-                    result += get_pixel(cycle_count, register).as_str();
+                    pixels.push(get_pixel(cycle_count, register));
                     cycle_count += 1;
 
-                    result += get_pixel(cycle_count, register).as_str();
+                    pixels.push(get_pixel(cycle_count, register));
                     cycle_count += 1;
 
                     register += value;
                 }
             }
         }
-        result
+        Ok(Grid(pixels.chunks(40).map(String::from_iter).collect()))
     }
 }
 
@@ -251,15 +255,15 @@ noop";
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_10::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 13140);
+        let solver = Solver2022_10::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 13140);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_10::from(EXAMPLE);
+        let solver = Solver2022_10::try_from(EXAMPLE).unwrap();
         assert_eq!(
-            solver.solve_second_part(),
+            solver.solve_second_part().unwrap().to_string(),
             "\
 ##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use pest::iterators::Pair;
 use pest::Parser;
 use std::collections::HashMap;
@@ -29,10 +29,12 @@ enum Operation {
 }
 
 impl Operation {
-    fn evaluate(&self, old: &u64) -> u64 {
+    fn evaluate(&self, old: &u64) -> Option<u64> {
         match self {
-            Operation::Add(left, right) => left.evaluate(old) + right.evaluate(old),
-            Operation::Multiply(left, right) => left.evaluate(old) * right.evaluate(old),
+            Operation::Add(left, right) => left.evaluate(old).checked_add(*right.evaluate(old)),
+            Operation::Multiply(left, right) => {
+                left.evaluate(old).checked_mul(*right.evaluate(old))
+            }
         }
     }
 }
@@ -63,17 +65,19 @@ impl Monkey {
         }
     }
 
-    fn throw<F>(&self, item: &u64, relief: F) -> (usize, u64)
+    fn throw<F>(&self, item: &u64, relief: F) -> Result<(usize, u64), SolveError>
     where
         F: Fn(u64) -> u64,
     {
-        let item = relief(self.operation.evaluate(item));
+        let item = relief(self.operation.evaluate(item).ok_or_else(|| {
+            SolveError::Unsupported(String::from("a worry level overflows 64 bits"))
+        })?);
         let monkey_index = if item % self.tester == 0 {
             self.if_true
         } else {
             self.if_false
         };
-        (monkey_index, item)
+        Ok((monkey_index, item))
     }
 }
 
@@ -83,32 +87,39 @@ pub struct Solver2022_11 {
 
 impl Default for Solver2022_11 {
     fn default() -> Self {
-        Solver2022_11::from(include_str!("input.txt"))
+        Solver2022_11::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_11 {
-    fn from(input: &str) -> Self {
-        let program = SantaParser::parse(Rule::Program, input).unwrap_or_else(|e| panic!("{}", e));
-        let monkey_pairs = program.peek().unwrap().into_inner();
+impl TryFrom<&str> for Solver2022_11 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let program = SantaParser::parse(Rule::Program, input)?;
+        let monkey_pairs = program
+            .peek()
+            .unwrap()
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::MonkeyInstruction);
         let mut monkeys = HashMap::new();
+        let mut targets = Vec::new();
 
         for monkey_pair in monkey_pairs {
             let pairs: Vec<Pair<Rule>> = monkey_pair.into_inner().collect();
-            let monkey_index: usize = pairs[0].as_str().parse().unwrap();
+            let monkey_index: usize = parse::pair_value(&pairs[0])?;
             let items: Vec<u64> = pairs[1]
                 .to_owned()
                 .into_inner()
-                .map(|i| i.as_str().parse().unwrap())
-                .collect();
+                .map(|i| parse::pair_value(&i))
+                .collect::<Result<_, _>>()?;
             let left_operand = match pairs[2].as_rule() {
                 Rule::Old => Operand::Old,
-                Rule::Number => Operand::Number(pairs[2].as_str().parse().unwrap()),
+                Rule::Number => Operand::Number(parse::pair_value(&pairs[2])?),
                 other => panic!("syntax error: operand expected, found {:?}", other),
             };
             let right_operand = match pairs[4].as_rule() {
                 Rule::Old => Operand::Old,
-                Rule::Number => Operand::Number(pairs[4].as_str().parse().unwrap()),
+                Rule::Number => Operand::Number(parse::pair_value(&pairs[4])?),
                 other => panic!("syntax error: operand expected, found {:?}", other),
             };
             let operation = match pairs[3].as_rule() {
@@ -116,21 +127,47 @@ impl From<&str> for Solver2022_11 {
                 Rule::Multiply => Operation::Multiply(left_operand, right_operand),
                 other => panic!("syntax error: operator expected, found {:?}", other),
             };
-            let tester: u64 = pairs[5].as_str().parse().unwrap();
-            let if_true: usize = pairs[6].as_str().parse().unwrap();
-            let if_false: usize = pairs[7].as_str().parse().unwrap();
+            let tester: u64 = parse::pair_value(&pairs[5])?;
+            if tester == 0 {
+                return Err(ParseError::at(
+                    input,
+                    pairs[5].as_str(),
+                    "expected a divisor greater than 0",
+                ));
+            }
+            let if_true: usize = parse::pair_value(&pairs[6])?;
+            let if_false: usize = parse::pair_value(&pairs[7])?;
+            targets.push((monkey_index, if_true, pairs[6].as_str()));
+            targets.push((monkey_index, if_false, pairs[7].as_str()));
             monkeys.insert(
                 monkey_index,
                 Monkey::new(items, operation, tester, if_true, if_false),
             );
         }
 
-        Self { monkeys }
+        for (monkey_index, target, span) in targets {
+            if target == monkey_index {
+                return Err(ParseError::at(
+                    input,
+                    span,
+                    format!("the monkey {monkey_index} throws to itself"),
+                ));
+            }
+            if !monkeys.contains_key(&target) {
+                return Err(ParseError::at(
+                    input,
+                    span,
+                    format!("there is no monkey {target}"),
+                ));
+            }
+        }
+
+        Ok(Self { monkeys })
     }
 }
 
 impl Solver2022_11 {
-    fn solve_part<F>(&self, rounds: usize, relief: F) -> u64
+    fn solve_part<F>(&self, rounds: usize, relief: F) -> Result<u64, SolveError>
     where
         F: Fn(u64) -> u64,
     {
@@ -143,15 +180,12 @@ impl Solver2022_11 {
                 for item in monkey.items.clone() {
                     let mut current_index = *index;
                     *investigation_map.entry(current_index).or_insert(0) += 1;
-                    let (mut next_index, mut item) = monkey.throw(&item, &relief).clone();
+                    let (mut next_index, mut item) = monkey.throw(&item, &relief)?;
                     while current_index <= next_index {
                         current_index = next_index;
                         *investigation_map.entry(current_index).or_insert(0) += 1;
-                        (next_index, item) = monkeys
-                            .get(&current_index)
-                            .unwrap()
-                            .throw(&item, &relief)
-                            .clone();
+                        (next_index, item) =
+                            monkeys.get(&current_index).unwrap().throw(&item, &relief)?;
                     }
                     new_items_map
                         .entry(next_index)
@@ -171,20 +205,26 @@ impl Solver2022_11 {
 
         let mut investigations: Vec<&u64> = investigation_map.values().collect();
         investigations.sort();
-        investigations.pop().unwrap() * investigations.pop().unwrap()
+        match (investigations.pop(), investigations.pop()) {
+            (Some(first), Some(second)) => Ok(first * second),
+            _ => Err(SolveError::NoSolution),
+        }
     }
 }
 
 impl Solver<u64, u64> for Solver2022_11 {
-    fn solve_first_part(&self) -> u64 {
+    fn solve_first_part(&self) -> Result<u64, SolveError> {
         self.solve_part(20, |x| x / 3)
     }
 
-    fn solve_second_part(&self) -> u64 {
+    fn solve_second_part(&self) -> Result<u64, SolveError> {
         let relief = self
             .monkeys
             .values()
-            .fold(1, |product, monkey| product * monkey.tester);
+            .try_fold(1u64, |product, monkey| product.checked_mul(monkey.tester))
+            .ok_or_else(|| {
+                SolveError::Unsupported(String::from("the divisors multiply beyond 64 bits"))
+            })?;
         self.solve_part(10000, move |x| x % relief)
     }
 }
@@ -224,7 +264,7 @@ mod tests {
 
     #[test]
     fn should_parse_input() {
-        let solver = Solver2022_11::from(EXAMPLE);
+        let solver = Solver2022_11::try_from(EXAMPLE).unwrap();
         assert_eq!(
             solver.monkeys,
             HashMap::<usize, Monkey>::from([
@@ -274,12 +314,33 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_11::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 10605);
+        let solver = Solver2022_11::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 10605);
     }
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_11::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 2713310158);
+        let solver = Solver2022_11::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 2713310158);
+    }
+
+    #[test]
+    fn should_reject_unknown_targets() {
+        assert_eq!(
+            Solver2022_11::try_from(EXAMPLE.replace("monkey 3", "monkey 4").as_str()).err(),
+            Some(ParseError::new(6, 31, "there is no monkey 4"))
+        );
+        assert_eq!(
+            Solver2022_11::try_from(
+                EXAMPLE
+                    .replace("true: throw to monkey 2", "true: throw to monkey 0")
+                    .as_str()
+            )
+            .err(),
+            Some(ParseError::new(5, 30, "the monkey 0 throws to itself"))
+        );
+        assert_eq!(
+            Solver2022_11::try_from(EXAMPLE.replace("by 23", "by 0").as_str()).err(),
+            Some(ParseError::new(4, 22, "expected a divisor greater than 0"))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::collections::VecDeque;
 
 pub struct Solver2022_12 {
@@ -9,41 +9,57 @@ pub struct Solver2022_12 {
 
 impl Default for Solver2022_12 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_12 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2022_12 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        if input.is_empty() {
+            return Err(ParseError::end_of_input(input, "a heightmap"));
+        }
         let rows: Vec<&str> = input.split("\n").collect();
         let x = rows[0].len();
         let y = rows.len();
-        let mut start_coordinates = (0, 0);
-        let mut end_coordinates = (0, 0);
+        let mut start_coordinates = None;
+        let mut end_coordinates = None;
         let mut points = vec![vec![0; x]; y];
         for (i, row) in rows.iter().enumerate() {
+            if row.len() != x {
+                return Err(ParseError::at(input, row, format!("expected {x} squares")));
+            }
             for (j, char) in row.chars().enumerate() {
                 match char {
                     'S' => {
-                        start_coordinates = (j, i);
+                        start_coordinates = Some((j, i));
                         points[i][j] = 0;
                     }
                     'E' => {
-                        end_coordinates = (j, i);
+                        end_coordinates = Some((j, i));
                         points[i][j] = 25;
                     }
                     'a'..='z' => {
                         points[i][j] = (char as usize) - ('a' as usize);
                     }
-                    _ => unreachable!(),
+                    other => {
+                        return Err(ParseError::new(
+                            i + 1,
+                            j + 1,
+                            format!("unexpected `{other}`"),
+                        ))
+                    }
                 }
             }
         }
-        Self {
-            start_coordinates,
-            end_coordinates,
+        Ok(Self {
+            start_coordinates: start_coordinates
+                .ok_or_else(|| ParseError::end_of_input(input, "the current position `S`"))?,
+            end_coordinates: end_coordinates
+                .ok_or_else(|| ParseError::end_of_input(input, "the best signal `E`"))?,
             points,
-        }
+        })
     }
 }
 
@@ -116,7 +132,7 @@ impl Solver2022_12 {
         result
     }
 
-    fn find_shortest_path_from_start(&self) -> usize {
+    fn find_shortest_path_from_start(&self) -> Result<usize, SolveError> {
         let mut distance_map = vec![vec![usize::MAX; self.points[0].len()]; self.points.len()];
         distance_map[self.start_coordinates.1][self.start_coordinates.0] = 0;
         let mut queue = VecDeque::from([self.start_coordinates]);
@@ -129,10 +145,13 @@ impl Solver2022_12 {
                 queue.push_back(neighbour);
             }
         }
-        distance_map[self.end_coordinates.1][self.end_coordinates.0]
+        match distance_map[self.end_coordinates.1][self.end_coordinates.0] {
+            usize::MAX => Err(SolveError::NoSolution),
+            distance => Ok(distance),
+        }
     }
 
-    fn find_shortest_path_from_anywhere(&self) -> usize {
+    fn find_shortest_path_from_anywhere(&self) -> Result<usize, SolveError> {
         let mut distance_map = vec![vec![usize::MAX; self.points[0].len()]; self.points.len()];
         distance_map[self.end_coordinates.1][self.end_coordinates.0] = 0;
         let mut queue = VecDeque::from([self.end_coordinates]);
@@ -142,22 +161,22 @@ impl Solver2022_12 {
             let neighbours = self.get_available_neighbours_reverse(current_position, &distance_map);
             for neighbour in neighbours {
                 if self.points[neighbour.1][neighbour.0] == 0 {
-                    return current_distance + 1;
+                    return Ok(current_distance + 1);
                 }
                 distance_map[neighbour.1][neighbour.0] = current_distance + 1;
                 queue.push_back(neighbour);
             }
         }
-        panic!("Couldn't find a solution")
+        Err(SolveError::NoSolution)
     }
 }
 
 impl Solver<usize, usize> for Solver2022_12 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         self.find_shortest_path_from_start()
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         self.find_shortest_path_from_anywhere()
     }
 }
@@ -175,13 +194,40 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_12::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 31);
+        let solver = Solver2022_12::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 31);
     }
 
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_12::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 29);
+        let solver = Solver2022_12::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 29);
+    }
+
+    #[test]
+    fn should_fail_without_path() {
+        let solver = Solver2022_12::try_from("SazE").unwrap();
+        assert_eq!(solver.solve_first_part(), Err(SolveError::NoSolution));
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
+    }
+
+    #[test]
+    fn should_reject_missing_squares() {
+        assert_eq!(
+            Solver2022_12::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a heightmap, found end of input"
+            ))
+        );
+        assert_eq!(
+            Solver2022_12::try_from("aE").err(),
+            Some(ParseError::new(
+                1,
+                3,
+                "expected the current position `S`, found end of input"
+            ))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 use pest::iterators::Pair;
 use pest::Parser;
@@ -16,10 +16,10 @@ fn cmp(left: &List, right: &List) -> Ordering {
         Ordering::Less
     } else if right.is_empty() {
         Ordering::Greater
-    } else if left[0] == right[0] {
-        left[1..].cmp(&right[1..])
     } else {
-        left[0].cmp(&right[0])
+        left[0]
+            .cmp(&right[0])
+            .then_with(|| left[1..].cmp(&right[1..]))
     }
 }
 
@@ -52,51 +52,55 @@ pub struct Solver2022_13 {
 
 impl Default for Solver2022_13 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-fn parse_list(list: Pair<Rule>) -> List {
+fn parse_list(list: Pair<Rule>) -> Result<List, ParseError> {
     list.into_inner()
         .map(|item| match item.as_rule() {
-            Rule::Number => ListItem::Integer(item.as_str().parse().unwrap()),
-            Rule::List => ListItem::List(parse_list(item)),
+            Rule::Number => Ok(ListItem::Integer(parse::pair_value(&item)?)),
+            Rule::List => Ok(ListItem::List(parse_list(item)?)),
             _ => unreachable!(),
         })
         .collect()
 }
 
-impl From<&str> for Solver2022_13 {
-    fn from(input: &str) -> Self {
-        let packets = SantaParser::parse(Rule::Packets, input).unwrap();
+impl TryFrom<&str> for Solver2022_13 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let packets = SantaParser::parse(Rule::Packets, input)?;
         let pair_of_packets = packets
             .peek()
             .unwrap()
             .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::Packet)
             .map(|packet| {
                 let mut pair = packet.into_inner();
-                (
-                    parse_list(pair.next().unwrap()),
-                    parse_list(pair.next().unwrap()),
-                )
+                Ok((
+                    parse_list(pair.next().unwrap())?,
+                    parse_list(pair.next().unwrap())?,
+                ))
             })
-            .collect();
-        Self { pair_of_packets }
+            .collect::<Result<_, ParseError>>()?;
+        Ok(Self { pair_of_packets })
     }
 }
 
 impl Solver<usize, usize> for Solver2022_13 {
-    fn solve_first_part(&self) -> usize {
-        self.pair_of_packets
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .pair_of_packets
             .iter()
             .enumerate()
             .fold(
                 0,
                 |sum, (i, (left, right))| if left <= right { sum + i + 1 } else { sum },
-            )
+            ))
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let first: &List = &vec![ListItem::List(vec![ListItem::Integer(2)])];
         let second: &List = &vec![ListItem::List(vec![ListItem::Integer(6)])];
 
@@ -119,7 +123,7 @@ impl Solver<usize, usize> for Solver2022_13 {
             .enumerate()
             .find(|&(_, list)| cmp(list, second) == Ordering::Equal)
             .unwrap();
-        (first_index + 1) * (second_index + 1)
+        Ok((first_index + 1) * (second_index + 1))
     }
 }
 
@@ -154,7 +158,7 @@ mod tests {
 
     #[test]
     fn should_parse_input() {
-        let solver = Solver2022_13::from(EXAMPLE);
+        let solver = Solver2022_13::try_from(EXAMPLE).unwrap();
         assert_eq!(
             solver.pair_of_packets[0..=1],
             vec![
@@ -194,12 +198,17 @@ mod tests {
 
     #[test]
     fn should_solve_first_part_example() {
-        let solver = Solver2022_13::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 13);
+        let solver = Solver2022_13::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 13);
     }
     #[test]
     fn should_solve_second_part_example() {
-        let solver = Solver2022_13::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 140);
+        let solver = Solver2022_13::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 140);
+    }
+    #[test]
+    fn should_compare_integer_with_equal_list() {
+        let solver = Solver2022_13::try_from("[[3],2]\n[3,1]\n\n[3,1]\n[[3],2]").unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::cmp::{max, min};
 
 #[derive(Copy, Clone, PartialEq)]
@@ -20,23 +20,55 @@ pub struct Solver2022_14 {
 
 impl Default for Solver2022_14 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_14 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2022_14 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let mut grid = [[Material::Air; 700]; 200];
         let paths = input.split("\n");
         for path in paths {
             let edges: Vec<(usize, usize)> = path
                 .split(" -> ")
                 .map(|edge| {
-                    let coordinates: Vec<usize> =
-                        edge.split(",").map(|val| val.parse().unwrap()).collect();
-                    (coordinates[0], coordinates[1])
+                    let (x, y) = edge
+                        .split_once(",")
+                        .ok_or_else(|| ParseError::at(input, edge, "expected `X,Y`"))?;
+                    let (x, y) = (parse::value(input, x)?, parse::value(input, y)?);
+                    if x < 700 && y < 198 {
+                        Ok((x, y))
+                    } else {
+                        Err(ParseError::at(
+                            input,
+                            edge,
+                            "the rock is outside of the cave",
+                        ))
+                    }
                 })
-                .collect();
+                .collect::<Result<_, ParseError>>()?;
+            if edges.len() < 2 {
+                return Err(ParseError::at(
+                    input,
+                    path,
+                    "expected a path of two points or more",
+                ));
+            }
+            if let Some(pair) = edges
+                .windows(2)
+                .find(|pair| pair[0].0 != pair[1].0 && pair[0].1 != pair[1].1)
+            {
+                return Err(ParseError::at(
+                    input,
+                    path,
+                    format!(
+                        "the line from {:?} to {:?} is not straight",
+                        pair[0], pair[1]
+                    ),
+                ));
+            }
             for i in 1..edges.len() {
                 let first_edge = edges[i - 1];
                 let second_edge = edges[i];
@@ -53,7 +85,7 @@ impl From<&str> for Solver2022_14 {
                 }
             }
         }
-        Self { grid }
+        Ok(Self { grid })
     }
 }
 
@@ -78,17 +110,17 @@ impl Solver2022_14 {
 }
 
 impl Solver<u32, u32> for Solver2022_14 {
-    fn solve_first_part(&self) -> u32 {
+    fn solve_first_part(&self) -> Result<u32, SolveError> {
         let mut mutated = self.clone();
         let mut result = 0;
         while let Ok(location) = mutated.drop_sand() {
             mutated.grid[location.1][location.0] = Material::Sand;
             result += 1;
         }
-        result
+        Ok(result)
     }
 
-    fn solve_second_part(&self) -> u32 {
+    fn solve_second_part(&self) -> Result<u32, SolveError> {
         let mut mutated = self.clone();
         let (max_y, _) = mutated
             .grid
@@ -104,7 +136,7 @@ impl Solver<u32, u32> for Solver2022_14 {
             mutated.grid[y][x] = Material::Sand;
             result += 1;
             if y == 0 {
-                return result;
+                return Ok(result);
             }
         }
     }
@@ -120,13 +152,33 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_14::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 24);
+        let solver = Solver2022_14::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 24);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_14::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 93);
+        let solver = Solver2022_14::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 93);
+    }
+
+    #[test]
+    fn should_reject_single_point_path() {
+        assert_eq!(
+            Solver2022_14::try_from("1,2").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a path of two points or more"
+            ))
+        );
+        assert_eq!(
+            Solver2022_14::try_from("1,2 -> 3,4").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "the line from (1, 2) to (3, 4) is not straight"
+            ))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 use regex::Regex;
 use std::collections::HashSet;
@@ -20,34 +20,31 @@ pub struct Solver2022_15 {
 
 impl Default for Solver2022_15 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_15 {
-    fn from(input: &'a str) -> Self {
+impl<'a> TryFrom<&'a str> for Solver2022_15 {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
         let re = Regex::new(
-            r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)",
+            r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$",
         )
         .unwrap();
-        Self {
+        Ok(Self {
             sensor_and_beacons: input
                 .split("\n")
                 .map(|row| {
-                    let positions = re.captures(row).unwrap();
-                    (
-                        Coordinate(
-                            positions.get(1).unwrap().as_str().parse().unwrap(),
-                            positions.get(2).unwrap().as_str().parse().unwrap(),
-                        ),
-                        Coordinate(
-                            positions.get(3).unwrap().as_str().parse().unwrap(),
-                            positions.get(4).unwrap().as_str().parse().unwrap(),
-                        ),
-                    )
+                    let positions = parse::captures(input, &re, row)?;
+                    let value = |i| parse::value(input, positions.get(i).unwrap().as_str());
+                    Ok((
+                        Coordinate(value(1)?, value(2)?),
+                        Coordinate(value(3)?, value(4)?),
+                    ))
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
@@ -74,7 +71,7 @@ impl Solver2022_15 {
         results.len()
     }
 
-    fn find_distress_beacon(&self, max_coordinate: i64) -> Coordinate {
+    fn find_distress_beacon(&self, max_coordinate: i64) -> Option<Coordinate> {
         let coverage_areas: Vec<(&Coordinate, i64)> = self
             .sensor_and_beacons
             .iter()
@@ -95,23 +92,25 @@ impl Solver2022_15 {
                     }
                 }
                 if !covered {
-                    return current_location;
+                    return Some(current_location);
                 }
                 i += 1;
             }
         }
-        panic!("beacon not found");
+        None
     }
 }
 
 impl Solver<usize, i64> for Solver2022_15 {
-    fn solve_first_part(&self) -> usize {
-        self.find_beacon_free_cells(2000000)
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self.find_beacon_free_cells(2000000))
     }
 
-    fn solve_second_part(&self) -> i64 {
-        let beacon = self.find_distress_beacon(4000000);
-        (beacon.0 * 4000000) + beacon.1
+    fn solve_second_part(&self) -> Result<i64, SolveError> {
+        let beacon = self
+            .find_distress_beacon(4000000)
+            .ok_or(SolveError::NoSolution)?;
+        Ok((beacon.0 * 4000000) + beacon.1)
     }
 }
 
@@ -137,13 +136,21 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_15::from(EXAMPLE);
+        let solver = Solver2022_15::try_from(EXAMPLE).unwrap();
         assert_eq!(solver.find_beacon_free_cells(10), 26);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_15::from(EXAMPLE);
-        assert_eq!(solver.find_distress_beacon(20), Coordinate(14, 11));
+        let solver = Solver2022_15::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.find_distress_beacon(20), Some(Coordinate(14, 11)));
+    }
+
+    #[test]
+    fn should_fail_when_every_cell_is_covered() {
+        let solver =
+            Solver2022_15::try_from("Sensor at x=10, y=10: closest beacon is at x=10, y=30")
+                .unwrap();
+        assert_eq!(solver.find_distress_beacon(20), None);
     }
 }
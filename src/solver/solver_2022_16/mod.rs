@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, ProgressHook, ProgressUpdate, SolveError, Solver};
 
 use regex::Regex;
 use std::cmp::{max, min};
@@ -40,36 +40,55 @@ pub struct Solver2022_16 {
 
 impl Default for Solver2022_16 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_16 {
-    fn from(input: &'a str) -> Self {
-        let re = Regex::new(r"Valve ([A-Z]+) has flow rate=(\d+); tunnels? leads? to valves? (.*)")
-            .unwrap();
+impl<'a> TryFrom<&'a str> for Solver2022_16 {
+    type Error = ParseError;
 
-        Self {
-            valves: input
-                .split("\n")
-                .map(|row| {
-                    let captures = re.captures(row).unwrap();
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let re =
+            Regex::new(r"^Valve ([A-Z]+) has flow rate=(\d+); tunnels? leads? to valves? (.*)$")
+                .unwrap();
+
+        let rows = input
+            .split("\n")
+            .map(|row| {
+                let captures = parse::captures(input, &re, row)?;
+                Ok((
+                    captures.get(1).unwrap().as_str(),
+                    parse::value(input, captures.get(2).unwrap().as_str())?,
+                    captures.get(3).unwrap().as_str().split(", ").collect(),
+                ))
+            })
+            .collect::<Result<Vec<(&str, i32, Vec<&str>)>, ParseError>>()?;
+        let names: HashSet<&str> = rows.iter().map(|(name, _, _)| *name).collect();
+        if let Some(tunnel) = rows
+            .iter()
+            .flat_map(|(_, _, tunnels)| tunnels)
+            .find(|tunnel| !names.contains(*tunnel))
+        {
+            return Err(ParseError::at(
+                input,
+                tunnel,
+                format!("there is no valve `{tunnel}`"),
+            ));
+        }
+        if !names.contains("AA") {
+            return Err(ParseError::end_of_input(input, "the valve `AA`"));
+        }
+        Ok(Self {
+            valves: rows
+                .into_iter()
+                .map(|(name, flow, tunnels)| {
                     (
-                        captures.get(1).unwrap().as_str().to_string(),
-                        Valve::new(
-                            captures.get(2).unwrap().as_str().parse().unwrap(),
-                            captures
-                                .get(3)
-                                .unwrap()
-                                .as_str()
-                                .split(", ")
-                                .map(|s| String::from(s))
-                                .collect(),
-                        ),
+                        name.to_string(),
+                        Valve::new(flow, tunnels.into_iter().map(String::from).collect()),
                     )
                 })
                 .collect(),
-        }
+        })
     }
 }
 
@@ -106,14 +125,29 @@ impl Solver2022_16 {
 }
 
 impl Solver<i32, i32> for Solver2022_16 {
-    fn solve_first_part(&self) -> i32 {
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        self.solve_first_part_with(&ProgressHook::none())
+    }
+
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
+        self.solve_second_part_with(&ProgressHook::none())
+    }
+
+    fn solve_first_part_with(&self, progress: &ProgressHook) -> Result<i32, SolveError> {
         let mut candidates: Vec<(i32, i32, &str, HashSet<String>)> = Vec::new();
         let shortest_path_map = self.get_shortest_paths_map();
         candidates.push((0, 30, "AA", HashSet::from(["AA".to_string()])));
         let mut winner = 0;
+        let mut states: u64 = 0;
 
         while let Some((score, remaining_minutes, current_point, visited_points)) = candidates.pop()
         {
+            states += 1;
+            progress.report(|| ProgressUpdate {
+                states,
+                best: Some(winner.into()),
+                iteration: None,
+            })?;
             let shortest_paths = shortest_path_map.get(current_point).unwrap();
             for (next_point, distance) in shortest_paths {
                 let remaining_minutes_after_open = remaining_minutes - distance - 1;
@@ -135,10 +169,10 @@ impl Solver<i32, i32> for Solver2022_16 {
                 }
             }
         }
-        winner
+        Ok(winner)
     }
 
-    fn solve_second_part(&self) -> i32 {
+    fn solve_second_part_with(&self, progress: &ProgressHook) -> Result<i32, SolveError> {
         let mut candidates: Vec<(i32, (i32, &str), (i32, &str), HashSet<String>)> = Vec::new();
         let shortest_path_map = self.get_shortest_paths_map();
         let mut sorted_flows: Vec<(&str, i32)> = self
@@ -150,8 +184,15 @@ impl Solver<i32, i32> for Solver2022_16 {
         sorted_flows.sort();
         candidates.push((0, (26, "AA"), (26, "AA"), HashSet::from(["AA".to_string()])));
         let mut winner = 0;
+        let mut states: u64 = 0;
 
         while let Some((score, player_state, elephant_state, visited_points)) = candidates.pop() {
+            states += 1;
+            progress.report(|| ProgressUpdate {
+                states,
+                best: Some(winner.into()),
+                iteration: None,
+            })?;
             for (remaining_minutes, current_point, other_state) in [
                 (player_state.0, player_state.1, elephant_state),
                 (elephant_state.0, elephant_state.1, player_state),
@@ -193,7 +234,7 @@ impl Solver<i32, i32> for Solver2022_16 {
                 }
             }
         }
-        winner
+        Ok(winner)
     }
 }
 
@@ -213,15 +254,31 @@ mod tests {
         Valve II has flow rate=0; tunnels lead to valves AA, JJ\n\
         Valve JJ has flow rate=21; tunnel leads to valve II";
 
+    #[test]
+    fn should_reject_unknown_valves() {
+        assert_eq!(
+            Solver2022_16::try_from("Valve AA has flow rate=0; tunnel leads to valve BB").err(),
+            Some(ParseError::new(1, 49, "there is no valve `BB`"))
+        );
+        assert_eq!(
+            Solver2022_16::try_from("Valve BB has flow rate=0; tunnel leads to valve BB").err(),
+            Some(ParseError::new(
+                1,
+                51,
+                "expected the valve `AA`, found end of input"
+            ))
+        );
+    }
+
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_16::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 1651);
+        let solver = Solver2022_16::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 1651);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_16::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 1707);
+        let solver = Solver2022_16::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 1707);
     }
 }
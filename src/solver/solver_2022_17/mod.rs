@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, ProgressHook, ProgressUpdate, SolveError, Solver};
 
 use std::collections::{HashMap, HashSet};
 
@@ -235,13 +235,22 @@ fn get_moves(input: &str) -> Vec<Move> {
     input.chars().map(|c| Move::from(c)).collect()
 }
 
-fn solve_puzzle(moves: &Vec<Move>, number_of_rocks: usize) -> usize {
+fn solve_puzzle(
+    moves: &Vec<Move>,
+    number_of_rocks: usize,
+    progress: &ProgressHook,
+) -> Result<usize, SolveError> {
     let mut chamber: Chamber = Chamber::new();
     let mut move_count = 0;
     let mut cycle_finder: HashMap<(usize, usize, String), (usize, usize)> = HashMap::new();
     let mut i = 0;
     let mut found_cycle = false;
     while i < number_of_rocks {
+        progress.report(|| ProgressUpdate {
+            states: cycle_finder.len() as u64,
+            best: Some(chamber.height as i64),
+            iteration: Some(i as u64),
+        })?;
         let rock_index = i % 5;
         if !found_cycle {
             let move_index = move_count % moves.len();
@@ -289,7 +298,7 @@ fn solve_puzzle(moves: &Vec<Move>, number_of_rocks: usize) -> usize {
         }
         i += 1;
     }
-    chamber.height
+    Ok(chamber.height)
 }
 
 pub struct Solver2022_17 {
@@ -298,25 +307,39 @@ pub struct Solver2022_17 {
 
 impl Default for Solver2022_17 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_17 {
-    fn from(input: &'a str) -> Self {
-        Self {
-            moves: get_moves(input),
+impl<'a> TryFrom<&'a str> for Solver2022_17 {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "<>")?;
+        if input.is_empty() {
+            return Err(ParseError::end_of_input(input, "a jet of gas"));
         }
+        Ok(Self {
+            moves: get_moves(input),
+        })
     }
 }
 
 impl Solver<usize, usize> for Solver2022_17 {
-    fn solve_first_part(&self) -> usize {
-        solve_puzzle(&self.moves, 2022)
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        self.solve_first_part_with(&ProgressHook::none())
+    }
+
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        self.solve_second_part_with(&ProgressHook::none())
     }
 
-    fn solve_second_part(&self) -> usize {
-        solve_puzzle(&self.moves, 1000000000000)
+    fn solve_first_part_with(&self, progress: &ProgressHook) -> Result<usize, SolveError> {
+        solve_puzzle(&self.moves, 2022, progress)
+    }
+
+    fn solve_second_part_with(&self, progress: &ProgressHook) -> Result<usize, SolveError> {
+        solve_puzzle(&self.moves, 1000000000000, progress)
     }
 }
 
@@ -377,13 +400,25 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_17::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 3068);
+        let solver = Solver2022_17::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 3068);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_17::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 1514285714288);
+        let solver = Solver2022_17::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 1514285714288);
+    }
+
+    #[test]
+    fn should_reject_empty_jet_pattern() {
+        assert_eq!(
+            Solver2022_17::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a jet of gas, found end of input"
+            ))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::cmp::{max, min};
 use std::collections::HashSet;
 
@@ -19,22 +19,29 @@ pub struct Solver2022_18 {
 
 impl Default for Solver2022_18 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_18 {
-    fn from(input: &'a str) -> Self {
+impl<'a> TryFrom<&'a str> for Solver2022_18 {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
         let droplets = input
             .split("\n")
             .map(|coordinate| {
-                let coordinate: Vec<usize> =
-                    coordinate.split(",").map(|i| i.parse().unwrap()).collect();
-                Coordinate(coordinate[0], coordinate[1], coordinate[2])
+                match coordinate
+                    .split(",")
+                    .map(|i| parse::value(input, i))
+                    .collect::<Result<Vec<usize>, _>>()?[..]
+                {
+                    [x, y, z] => Ok(Coordinate(x, y, z)),
+                    _ => Err(ParseError::at(input, coordinate, "expected `X,Y,Z`")),
+                }
             })
-            .collect();
+            .collect::<Result<_, ParseError>>()?;
 
-        Self { droplets }
+        Ok(Self { droplets })
     }
 }
 impl Solver2022_18 {
@@ -64,7 +71,7 @@ impl Solver2022_18 {
 }
 
 impl Solver<usize, usize> for Solver2022_18 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let droplet_set: HashSet<Coordinate> = HashSet::from_iter(self.droplets.iter().copied());
         let mut result = self.droplets.len() * 6;
 
@@ -79,9 +86,9 @@ impl Solver<usize, usize> for Solver2022_18 {
                 result -= 2;
             }
         }
-        result
+        Ok(result)
     }
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let max_coordinates = self.get_max();
         let min_coordinates = self.get_min();
         let reduced_max_coordinates = max_coordinates - min_coordinates;
@@ -179,7 +186,7 @@ impl Solver<usize, usize> for Solver2022_18 {
                 result += 1;
             }
         }
-        result
+        Ok(result)
     }
 }
 #[cfg(test)]
@@ -204,13 +211,13 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_18::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 64);
+        let solver = Solver2022_18::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 64);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_18::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 58);
+        let solver = Solver2022_18::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 58);
     }
 }
@@ -0,0 +1,220 @@
+use super::{parse, ParseError, ProgressHook, ProgressUpdate, SolveError, Solver};
+
+use regex::Regex;
+use std::cmp::max;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(PartialEq, Debug)]
+struct Blueprint {
+    id: i32,
+    // The ore, clay and obsidian each kind of robot costs, indexed like the resources.
+    costs: [[i32; 3]; 4],
+}
+
+#[derive(Clone, Copy)]
+struct State {
+    remaining_minutes: i32,
+    robots: [i32; 3],
+    resources: [i32; 3],
+    // Geode robots are counted by the geodes they will have cracked at the end.
+    geodes: i32,
+}
+
+impl Blueprint {
+    fn get_max_useful_robots(&self) -> [i32; 3] {
+        let mut max_robots = [0; 3];
+        for costs in &self.costs {
+            for (resource, cost) in costs.iter().enumerate() {
+                max_robots[resource] = max(max_robots[resource], *cost);
+            }
+        }
+        max_robots
+    }
+
+    // Builds a geode robot whenever there is enough obsidian and an obsidian robot every minute
+    // for free, which can't crack fewer geodes than any real schedule.
+    fn get_max_possible_geodes(&self, state: &State) -> i32 {
+        let mut obsidian = state.resources[OBSIDIAN];
+        let mut geodes = state.geodes;
+        let minutes = (1..=state.remaining_minutes).rev();
+        for (remaining_minutes, obsidian_robots) in minutes.zip(state.robots[OBSIDIAN]..) {
+            if obsidian >= self.costs[GEODE][OBSIDIAN] {
+                obsidian -= self.costs[GEODE][OBSIDIAN];
+                geodes += remaining_minutes - 1;
+            }
+            obsidian += obsidian_robots;
+        }
+        geodes
+    }
+
+    // Waits until the robot is affordable and builds it, if that is possible in time.
+    fn build(&self, state: &State, robot: usize) -> Option<State> {
+        let mut waiting_minutes = 0;
+        for (resource, cost) in self.costs[robot].iter().enumerate() {
+            let missing = cost - state.resources[resource];
+            if missing > 0 {
+                if state.robots[resource] == 0 {
+                    return None;
+                }
+                let minutes = (missing + state.robots[resource] - 1) / state.robots[resource];
+                waiting_minutes = max(waiting_minutes, minutes);
+            }
+        }
+        let remaining_minutes = state.remaining_minutes - waiting_minutes - 1;
+        if remaining_minutes <= 0 {
+            return None;
+        }
+        let mut next_state = State {
+            remaining_minutes,
+            ..*state
+        };
+        for resource in 0..3 {
+            next_state.resources[resource] +=
+                state.robots[resource] * (waiting_minutes + 1) - self.costs[robot][resource];
+        }
+        if robot == GEODE {
+            next_state.geodes += remaining_minutes;
+        } else {
+            next_state.robots[robot] += 1;
+        }
+        Some(next_state)
+    }
+
+    fn get_max_geodes(&self, minutes: i32, progress: &ProgressHook) -> Result<i32, SolveError> {
+        let max_robots = self.get_max_useful_robots();
+        let mut candidates = vec![State {
+            remaining_minutes: minutes,
+            robots: [1, 0, 0],
+            resources: [0; 3],
+            geodes: 0,
+        }];
+        let mut winner = 0;
+        let mut states: u64 = 0;
+
+        while let Some(state) = candidates.pop() {
+            states += 1;
+            progress.report(|| ProgressUpdate {
+                states,
+                best: Some(winner.into()),
+                iteration: Some(self.id as u64),
+            })?;
+            winner = max(winner, state.geodes);
+            if self.get_max_possible_geodes(&state) <= winner {
+                continue;
+            }
+            // The geode robot is pushed last to be tried first, which raises the winner early.
+            for robot in [ORE, CLAY, OBSIDIAN, GEODE] {
+                if robot != GEODE && state.robots[robot] >= max_robots[robot] {
+                    continue;
+                }
+                if let Some(next_state) = self.build(&state, robot) {
+                    candidates.push(next_state);
+                }
+            }
+        }
+        Ok(winner)
+    }
+}
+
+pub struct Solver2022_19 {
+    blueprints: Vec<Blueprint>,
+}
+
+impl Default for Solver2022_19 {
+    fn default() -> Self {
+        Self::try_from(include_str!("input.txt")).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Solver2022_19 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re = Regex::new(
+            r"^Blueprint (\d+): Each ore robot costs (\d+) ore\. Each clay robot costs (\d+) ore\. Each obsidian robot costs (\d+) ore and (\d+) clay\. Each geode robot costs (\d+) ore and (\d+) obsidian\.$",
+        )
+        .unwrap();
+
+        Ok(Self {
+            blueprints: input
+                .split("\n")
+                .map(|row| {
+                    let captures = parse::captures(input, &re, row)?;
+                    let value = |i| parse::value(input, captures.get(i).unwrap().as_str());
+                    Ok(Blueprint {
+                        id: value(1)?,
+                        costs: [
+                            [value(2)?, 0, 0],
+                            [value(3)?, 0, 0],
+                            [value(4)?, value(5)?, 0],
+                            [value(6)?, 0, value(7)?],
+                        ],
+                    })
+                })
+                .collect::<Result<_, ParseError>>()?,
+        })
+    }
+}
+
+impl Solver<i32, i32> for Solver2022_19 {
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        self.solve_first_part_with(&ProgressHook::none())
+    }
+
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
+        self.solve_second_part_with(&ProgressHook::none())
+    }
+
+    fn solve_first_part_with(&self, progress: &ProgressHook) -> Result<i32, SolveError> {
+        let mut quality_levels = 0;
+        for blueprint in &self.blueprints {
+            quality_levels += blueprint.id * blueprint.get_max_geodes(24, progress)?;
+        }
+        Ok(quality_levels)
+    }
+
+    fn solve_second_part_with(&self, progress: &ProgressHook) -> Result<i32, SolveError> {
+        let mut product = 1;
+        for blueprint in self.blueprints.iter().take(3) {
+            product *= blueprint.get_max_geodes(32, progress)?;
+        }
+        Ok(product)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = "\
+        Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.\n\
+        Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.";
+
+    #[test]
+    fn should_parse_blueprints() {
+        let solver = Solver2022_19::try_from(EXAMPLE).unwrap();
+        assert_eq!(
+            solver.blueprints[1],
+            Blueprint {
+                id: 2,
+                costs: [[2, 0, 0], [3, 0, 0], [3, 8, 0], [3, 0, 12]],
+            }
+        );
+    }
+
+    #[test]
+    fn should_solve_first_part() {
+        let solver = Solver2022_19::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 33);
+    }
+
+    #[test]
+    fn should_solve_second_part() {
+        let solver = Solver2022_19::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 56 * 62);
+    }
+}
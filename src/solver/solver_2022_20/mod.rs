@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2022_20 {
     numbers: Vec<isize>,
@@ -6,17 +6,26 @@ pub struct Solver2022_20 {
 
 impl Default for Solver2022_20 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_20 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2022_20 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let numbers = input
             .split("\n")
-            .map(|number| number.parse().unwrap())
-            .collect();
-        Self { numbers }
+            .map(|number| parse::value(input, number))
+            .collect::<Result<Vec<isize>, _>>()?;
+        // Mixing moves numbers around the others, and the coordinates are counted from zero.
+        if numbers.len() < 2 {
+            return Err(ParseError::end_of_input(input, "two numbers or more"));
+        }
+        if !numbers.contains(&0) {
+            return Err(ParseError::new(1, 1, "expected a `0` in the file"));
+        }
+        Ok(Self { numbers })
     }
 }
 
@@ -56,12 +65,12 @@ impl Solver2022_20 {
 }
 
 impl Solver<isize, isize> for Solver2022_20 {
-    fn solve_first_part(&self) -> isize {
-        self.decrypt(1, 1)
+    fn solve_first_part(&self) -> Result<isize, SolveError> {
+        Ok(self.decrypt(1, 1))
     }
 
-    fn solve_second_part(&self) -> isize {
-        self.decrypt(811589153, 10)
+    fn solve_second_part(&self) -> Result<isize, SolveError> {
+        Ok(self.decrypt(811589153, 10))
     }
 }
 
@@ -81,13 +90,29 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_20::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 3);
+        let solver = Solver2022_20::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 3);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_20::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 1623178306);
+        let solver = Solver2022_20::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 1623178306);
+    }
+
+    #[test]
+    fn should_reject_file_without_zero() {
+        assert_eq!(
+            Solver2022_20::try_from("1").err(),
+            Some(ParseError::new(
+                1,
+                2,
+                "expected two numbers or more, found end of input"
+            ))
+        );
+        assert_eq!(
+            Solver2022_20::try_from("1\n2").err(),
+            Some(ParseError::new(1, 1, "expected a `0` in the file"))
+        );
     }
 }
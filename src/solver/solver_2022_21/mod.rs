@@ -1,9 +1,9 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 use pest::iterators::Pair;
 use pest::Parser;
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(pest_derive::Parser)]
 #[grammar = "solver/solver_2022_21/grammar.pest"]
@@ -18,38 +18,102 @@ struct Analyzer<'a> {
 }
 
 impl<'a> Analyzer<'a> {
-    pub fn new(input: &'a str) -> Self {
-        let pairs = SantaParser::parse(Rule::Program, input).unwrap_or_else(|e| panic!("{}", e));
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        let pairs = SantaParser::parse(Rule::Program, input)?;
         let program = pairs.peek().unwrap();
         let mut rule_map = HashMap::<&str, Vec<Pair<Rule>>>::new();
-        for statement in program.into_inner() {
+        for statement in program
+            .into_inner()
+            .filter(|pair| pair.as_rule() == Rule::Statement)
+        {
             let rules: Vec<Pair<Rule>> = statement.into_inner().collect();
             let lhs = rules[0].as_str();
-            rule_map.insert(lhs, rules[1].clone().into_inner().collect());
+            let pairs: Vec<Pair<Rule>> = rules[1].clone().into_inner().collect();
+            if let [number] = &pairs[..] {
+                parse::pair_value::<usize, _>(number)?;
+            }
+            rule_map.insert(lhs, pairs);
+        }
+        let operands = |pairs: &[Pair<'a, Rule>]| match pairs {
+            [left, _, right] => vec![left.clone(), right.clone()],
+            _ => Vec::new(),
+        };
+        if let Some(monkey) = rule_map
+            .values()
+            .flat_map(|pairs| operands(pairs))
+            .filter(|monkey| !rule_map.contains_key(monkey.as_str()))
+            .min_by_key(|monkey| monkey.as_span().start())
+        {
+            return Err(ParseError::at(
+                input,
+                monkey.as_str(),
+                format!("there is no monkey `{}`", monkey.as_str()),
+            ));
+        }
+        if !rule_map.contains_key("root") {
+            return Err(ParseError::end_of_input(input, "the monkey `root`"));
+        }
+        // A monkey waiting on itself would never yell, and the solver would wait forever.
+        let mut resolved = HashSet::new();
+        let mut pending: Vec<&str> = rule_map.keys().copied().collect();
+        while !pending.is_empty() {
+            let count = pending.len();
+            pending.retain(|monkey| {
+                if operands(&rule_map[monkey])
+                    .iter()
+                    .all(|operand| resolved.contains(operand.as_str()))
+                {
+                    resolved.insert(*monkey);
+                    return false;
+                }
+                true
+            });
+            if pending.len() == count {
+                let monkey = pending.iter().min_by_key(|monkey| monkey.as_ptr()).unwrap();
+                return Err(ParseError::at(
+                    input,
+                    monkey,
+                    format!("the monkey `{monkey}` depends on itself"),
+                ));
+            }
         }
-        Self {
+        Ok(Self {
             rule_map,
             value_map: HashMap::new(),
-        }
+        })
     }
 
-    pub fn evaluate_ident(self: &'a Analyzer<'a>, variable: &'a str) -> Option<usize> {
+    /// Returns the number `variable` yells, or `None` while it still waits for its operands.
+    pub fn evaluate_ident(
+        self: &'a Analyzer<'a>,
+        variable: &'a str,
+    ) -> Result<Option<usize>, SolveError> {
         match self.value_map.get(variable) {
-            Some(result) => Some(*result),
+            Some(result) => Ok(Some(*result)),
             None => {
                 let pairs = self.rule_map.get(variable).unwrap();
                 if pairs.len() == 1 {
-                    pairs[0].as_str().parse().ok()
+                    Ok(pairs[0].as_str().parse().ok())
                 } else if pairs.len() == 3 {
-                    let left = self.value_map.get(pairs[0].as_str())?;
-                    let right = self.value_map.get(pairs[2].as_str())?;
+                    let (Some(left), Some(right)) = (
+                        self.value_map.get(pairs[0].as_str()),
+                        self.value_map.get(pairs[2].as_str()),
+                    ) else {
+                        return Ok(None);
+                    };
                     match pairs[1].as_rule() {
-                        Rule::Add => Some(left + right),
-                        Rule::Subtract => Some(left - right),
-                        Rule::Multiply => Some(left * right),
-                        Rule::Divide => Some(left / right),
+                        Rule::Add => left.checked_add(*right),
+                        Rule::Subtract => left.checked_sub(*right),
+                        Rule::Multiply => left.checked_mul(*right),
+                        Rule::Divide => left.checked_div(*right),
                         other => panic!("syntax error: operation cannot be {:?}", other),
                     }
+                    .map(Some)
+                    .ok_or_else(|| {
+                        SolveError::Unsupported(format!(
+                            "the monkey `{variable}` yells a number out of range"
+                        ))
+                    })
                 } else {
                     panic!(
                         "syntax error: lhs cannot have more than three tokens. Found: {:?}",
@@ -60,18 +124,18 @@ impl<'a> Analyzer<'a> {
         }
     }
 
-    pub fn get_value(&mut self, variable: &'a str) -> usize {
+    pub fn get_value(&mut self, variable: &'a str) -> Result<usize, SolveError> {
         loop {
             for key in self.rule_map.keys() {
-                match self.evaluate_ident(key) {
+                match self.evaluate_ident(key)? {
                     Some(value) => {
                         self.value_map.insert(key, value);
                     }
                     None => {}
                 };
             }
-            if let Some(value) = self.evaluate_ident(variable) {
-                return value;
+            if let Some(value) = self.evaluate_ident(variable)? {
+                return Ok(value);
             }
         }
     }
@@ -109,62 +173,71 @@ pub struct Solver2022_21<'a> {
 
 impl<'a> Default for Solver2022_21<'a> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2022_21<'a> {
-    fn from(input: &'a str) -> Self {
-        Self {
-            analyzer: Cow::Owned(Analyzer::new(input)),
-        }
+impl<'a> TryFrom<&'a str> for Solver2022_21<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            analyzer: Cow::Owned(Analyzer::new(input)?),
+        })
     }
 }
 
 impl<'a> Solver<usize, usize> for Solver2022_21<'a> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut analyzer = self.analyzer.to_owned().into_owned();
         analyzer.get_value("root")
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut analyzer = self.analyzer.to_owned().into_owned();
+        if analyzer.rule_map["root"].len() != 3 || !analyzer.is_parent("root", "humn") {
+            return Err(SolveError::Unsupported(
+                "the monkey `root` doesn't compare a number that depends on `humn`".to_string(),
+            ));
+        }
         let (left, right) = analyzer.get_children("root");
         let (mut human_parent, mut other_value) = if analyzer.is_parent(left, "humn") {
-            (left, analyzer.get_value(right))
+            (left, analyzer.get_value(right)?)
         } else {
-            (right, analyzer.get_value(left))
+            (right, analyzer.get_value(left)?)
         };
         let mut result = other_value;
         while human_parent != "humn" {
             let (left, right) = analyzer.get_children(human_parent);
             let operator = analyzer.get_operator(human_parent);
             (human_parent, other_value) = if analyzer.is_parent(left, "humn") {
-                (left, analyzer.get_value(right))
+                (left, analyzer.get_value(right)?)
             } else {
-                (right, analyzer.get_value(left))
+                (right, analyzer.get_value(left)?)
             };
+            // Without a number in range for `humn`, the two sides of `root` can't be equal.
             result = match operator {
-                Rule::Add => result - other_value,
+                Rule::Add => result.checked_sub(other_value),
                 Rule::Subtract => {
                     if analyzer.is_parent(left, "humn") {
-                        result + other_value
+                        result.checked_add(other_value)
                     } else {
-                        other_value - result
+                        other_value.checked_sub(result)
                     }
                 }
-                Rule::Multiply => result / other_value,
+                Rule::Multiply => result.checked_div(other_value),
                 Rule::Divide => {
                     if analyzer.is_parent(left, "humn") {
-                        result * other_value
+                        result.checked_mul(other_value)
                     } else {
-                        other_value / result
+                        other_value.checked_div(result)
                     }
                 }
                 other => panic!("syntax error: operation cannot be {:?}", other),
-            };
+            }
+            .ok_or(SolveError::NoSolution)?;
         }
-        result
+        Ok(result)
     }
 }
 
@@ -191,11 +264,66 @@ mod find_start_of_message_marker {
 
     #[test]
     fn should_solve_first_part() {
-        assert_eq!(Solver2022_21::from(EXAMPLE).solve_first_part(), 152);
+        assert_eq!(
+            Solver2022_21::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            152
+        );
+    }
+
+    #[test]
+    fn should_reject_missing_monkeys() {
+        assert_eq!(
+            Solver2022_21::try_from("root: aaaa + bbbb\naaaa: 1").err(),
+            Some(ParseError::new(1, 14, "there is no monkey `bbbb`"))
+        );
+        assert_eq!(
+            Solver2022_21::try_from("root: aaaa + aaaa\naaaa: root * root").err(),
+            Some(ParseError::new(1, 1, "the monkey `root` depends on itself"))
+        );
+        assert_eq!(
+            Solver2022_21::try_from("aaaa: 1").err(),
+            Some(ParseError::new(
+                1,
+                8,
+                "expected the monkey `root`, found end of input"
+            ))
+        );
+    }
+
+    #[test]
+    fn should_fail_on_numbers_out_of_range() {
+        let solver = Solver2022_21::try_from("root: aaaa - humn\naaaa: 1\nhumn: 2").unwrap();
+        assert_eq!(
+            solver.solve_first_part(),
+            Err(SolveError::Unsupported(
+                "the monkey `root` yells a number out of range".to_string()
+            ))
+        );
+        let solver = Solver2022_21::try_from("root: aaaa + aaaa\naaaa: 1").unwrap();
+        assert!(matches!(
+            solver.solve_second_part(),
+            Err(SolveError::Unsupported(_))
+        ));
+        let solver = Solver2022_21::try_from(
+            "root: aaaa + bbbb\naaaa: 1\nbbbb: humn + cccc\ncccc: 2\nhumn: 0",
+        );
+        assert_eq!(
+            solver.unwrap().solve_second_part(),
+            Err(SolveError::NoSolution)
+        );
     }
 
     #[test]
     fn should_solve_second_part() {
-        assert_eq!(Solver2022_21::from(EXAMPLE).solve_second_part(), 301);
+        assert_eq!(
+            Solver2022_21::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            301
+        );
     }
 }
@@ -1,6 +1,7 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 use regex::Regex;
+use std::collections::{HashMap, VecDeque};
 
 #[derive(PartialEq, Debug)]
 enum Instruction {
@@ -9,12 +10,12 @@ enum Instruction {
     Left,
 }
 
-impl From<&str> for Instruction {
-    fn from(value: &str) -> Self {
+impl Instruction {
+    fn parse(input: &str, value: &str) -> Result<Self, ParseError> {
         match value {
-            "R" => Instruction::Right,
-            "L" => Instruction::Left,
-            other => Instruction::Move(other.parse().unwrap()),
+            "R" => Ok(Instruction::Right),
+            "L" => Ok(Instruction::Left),
+            other => Ok(Instruction::Move(parse::value(input, other)?)),
         }
     }
 }
@@ -26,13 +27,15 @@ enum Cell {
     Wall,
 }
 
-impl From<char> for Cell {
-    fn from(value: char) -> Self {
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
         match value {
-            ' ' => Cell::Portal,
-            '.' => Cell::Open,
-            '#' => Cell::Wall,
-            other => panic!("Illegal argument: {}", other),
+            ' ' => Ok(Cell::Portal),
+            '.' => Ok(Cell::Open),
+            '#' => Ok(Cell::Wall),
+            other => Err(format!("unexpected `{other}`")),
         }
     }
 }
@@ -127,6 +130,188 @@ impl Map {
     }
 }
 
+type Vector = [isize; 3];
+
+fn neg(v: Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn dot(a: Vector, b: Vector) -> isize {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[derive(Clone, Copy)]
+struct Face {
+    x: usize,
+    y: usize,
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    fn direction(&self, direction: usize) -> Vector {
+        match direction {
+            0 => self.right,
+            1 => self.down,
+            2 => neg(self.right),
+            3 => neg(self.down),
+            other => panic!("Illegal direction: {}", other),
+        }
+    }
+}
+
+// Cell centers live on a cube with doubled coordinates, so that a face of
+// size `n` is at distance `n` from the origin and every center is an integer.
+struct Cube {
+    size: usize,
+    faces: Vec<Face>,
+}
+
+impl Cube {
+    fn fold(map: &Map) -> Result<Self, SolveError> {
+        let not_a_cube = || SolveError::Unsupported("the map is not a cube net".to_string());
+        let area = map
+            .cells
+            .iter()
+            .flatten()
+            .filter(|cell| cell != &&Cell::Portal)
+            .count();
+        let size = (1..).find(|n| 6 * n * n >= area).unwrap();
+        if 6 * size * size != area {
+            return Err(not_a_cube());
+        }
+
+        let mut faces = HashMap::new();
+        let mut queue = VecDeque::new();
+        let start = (0..map.cells[0].len() / size)
+            .find(|fx| map.is_valid_cell(fx * size, 0))
+            .ok_or_else(not_a_cube)?;
+        queue.push_back((
+            (start, 0usize),
+            Face {
+                x: start * size,
+                y: 0,
+                normal: [0, 0, 1],
+                right: [1, 0, 0],
+                down: [0, 1, 0],
+            },
+        ));
+        while let Some((position, face)) = queue.pop_front() {
+            if faces.contains_key(&position) {
+                continue;
+            }
+            faces.insert(position, face);
+            let (fx, fy) = position;
+            let neighbours = [
+                ((fx + 1, fy), neg(face.normal), face.down, face.right),
+                ((fx, fy + 1), face.right, neg(face.normal), face.down),
+                (
+                    (fx.wrapping_sub(1), fy),
+                    face.normal,
+                    face.down,
+                    neg(face.right),
+                ),
+                (
+                    (fx, fy.wrapping_sub(1)),
+                    face.right,
+                    face.normal,
+                    neg(face.down),
+                ),
+            ];
+            for ((nx, ny), right, down, normal) in neighbours {
+                if map.is_valid_cell(nx.wrapping_mul(size), ny.wrapping_mul(size)) {
+                    queue.push_back((
+                        (nx, ny),
+                        Face {
+                            x: nx * size,
+                            y: ny * size,
+                            normal,
+                            right,
+                            down,
+                        },
+                    ));
+                }
+            }
+        }
+
+        let faces: Vec<Face> = faces.into_values().collect();
+        let mut normals: Vec<Vector> = faces.iter().map(|face| face.normal).collect();
+        normals.sort();
+        normals.dedup();
+        if faces.len() != 6 || normals.len() != 6 {
+            return Err(not_a_cube());
+        }
+        Ok(Self { size, faces })
+    }
+
+    fn face_at(&self, x: usize, y: usize) -> &Face {
+        self.faces
+            .iter()
+            .find(|face| {
+                (face.x..face.x + self.size).contains(&x)
+                    && (face.y..face.y + self.size).contains(&y)
+            })
+            .unwrap()
+    }
+
+    fn face_with_normal(&self, normal: Vector) -> &Face {
+        self.faces
+            .iter()
+            .find(|face| face.normal == normal)
+            .unwrap()
+    }
+
+    fn find_next_cell(
+        &self,
+        map: &Map,
+        x: usize,
+        y: usize,
+        direction: usize,
+    ) -> (usize, usize, usize) {
+        let n = self.size as isize;
+        let face = self.face_at(x, y);
+        let (i, j) = ((x - face.x) as isize, (y - face.y) as isize);
+        let (next_i, next_j) = match direction {
+            0 => (i + 1, j),
+            1 => (i, j + 1),
+            2 => (i - 1, j),
+            3 => (i, j - 1),
+            other => panic!("Illegal direction: {}", other),
+        };
+        let next = if (0..n).contains(&next_i) && (0..n).contains(&next_j) {
+            (
+                face.x + next_i as usize,
+                face.y + next_j as usize,
+                direction,
+            )
+        } else {
+            let step = face.direction(direction);
+            let center: Vector = [0, 1, 2].map(|k| {
+                face.normal[k] * n
+                    + face.right[k] * (2 * i + 1 - n)
+                    + face.down[k] * (2 * j + 1 - n)
+                    + step[k]
+                    - face.normal[k]
+            });
+            let next_face = self.face_with_normal(step);
+            let next_direction = (0..4)
+                .find(|d| next_face.direction(*d) == neg(face.normal))
+                .unwrap();
+            (
+                next_face.x + ((dot(center, next_face.right) + n - 1) / 2) as usize,
+                next_face.y + ((dot(center, next_face.down) + n - 1) / 2) as usize,
+                next_direction,
+            )
+        };
+        if map.cells[next.1][next.0] == Cell::Wall {
+            (x, y, direction)
+        } else {
+            next
+        }
+    }
+}
+
 pub struct Solver2022_22 {
     map: Map,
     instructions: Vec<Instruction>,
@@ -134,37 +319,62 @@ pub struct Solver2022_22 {
 
 impl Default for Solver2022_22 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2022_22 {
-    fn from(input: &str) -> Self {
-        let (map, instructions) = input.split_once("\n\n").unwrap();
+impl TryFrom<&str> for Solver2022_22 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (map, path) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a path"))?;
         let cells = map
             .split("\n")
-            .map(|row| row.chars().map(|c| Cell::from(c)).collect())
-            .collect();
-        let re = Regex::new(r"(R|L|\d+)").unwrap();
+            .enumerate()
+            .map(|(y, row)| {
+                row.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        Cell::try_from(c).map_err(|message| ParseError::new(y + 1, x + 1, message))
+                    })
+                    .collect()
+            })
+            .collect::<Result<_, _>>()?;
+        let re = Regex::new(r"R|L|\d+").unwrap();
+        let mut end = 0;
         let instructions = re
-            .captures_iter(instructions)
-            .map(|captures| Instruction::from(&captures[1]))
-            .collect();
+            .find_iter(path)
+            .map(|token| {
+                if token.start() != end {
+                    return Err(ParseError::unexpected(input, &path[end..token.start()]));
+                }
+                end = token.end();
+                Instruction::parse(input, token.as_str())
+            })
+            .collect::<Result<_, _>>()?;
+        if end != path.len() {
+            return Err(ParseError::unexpected(input, &path[end..]));
+        }
         let map = Map { cells };
-        Self { map, instructions }
+        Ok(Self { map, instructions })
     }
 }
 
-impl Solver<usize, isize> for Solver2022_22 {
-    fn solve_first_part(&self) -> usize {
-        let mut player = (
-            self.map.cells[0]
-                .iter()
-                .position(|c| c == &Cell::Open)
-                .unwrap(),
-            0usize,
-            0usize,
-        );
+impl Solver2022_22 {
+    fn start(&self) -> Result<(usize, usize, usize), SolveError> {
+        let x = self.map.cells[0]
+            .iter()
+            .position(|c| c == &Cell::Open)
+            .ok_or(SolveError::NoSolution)?;
+        Ok((x, 0, 0))
+    }
+}
+
+impl Solver<usize, usize> for Solver2022_22 {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        let mut player = self.start()?;
 
         for instruction in &self.instructions {
             match instruction {
@@ -187,11 +397,34 @@ impl Solver<usize, isize> for Solver2022_22 {
             }
         }
 
-        1000 * (player.1 + 1) + 4 * (player.0 + 1) + player.2
+        Ok(1000 * (player.1 + 1) + 4 * (player.0 + 1) + player.2)
     }
 
-    fn solve_second_part(&self) -> isize {
-        6032
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        let cube = Cube::fold(&self.map)?;
+        let mut player = self.start()?;
+
+        for instruction in &self.instructions {
+            match instruction {
+                Instruction::Move(distance) => {
+                    for _ in 0..*distance {
+                        let next = cube.find_next_cell(&self.map, player.0, player.1, player.2);
+                        if next == player {
+                            break;
+                        }
+                        player = next;
+                    }
+                }
+                Instruction::Right => {
+                    player.2 = (player.2 + 1) % 4;
+                }
+                Instruction::Left => {
+                    player.2 = player.2.checked_sub(1).unwrap_or(3);
+                }
+            }
+        }
+
+        Ok(1000 * (player.1 + 1) + 4 * (player.0 + 1) + player.2)
     }
 }
 
@@ -218,13 +451,46 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2022_22::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 6032);
+        let solver = Solver2022_22::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 6032);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2022_22::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 6032);
+        let solver = Solver2022_22::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 5031);
+    }
+
+    #[test]
+    fn should_walk_around_the_cube_of_any_net() {
+        let empty_cube = |net: &[&str], path: &str| {
+            let map: Vec<String> = net
+                .iter()
+                .flat_map(|row| {
+                    let row: String = row
+                        .chars()
+                        .flat_map(|c| [if c == '#' { '.' } else { ' ' }; 3])
+                        .collect();
+                    [row.clone(), row.clone(), row]
+                })
+                .collect();
+            format!("{}\n\n{path}", map.join("\n"))
+        };
+        let nets = [
+            &[" ##", " # ", "## ", "#  "][..],
+            &["  # ", "### ", "  ##"][..],
+        ];
+        for net in nets {
+            for path in ["12", "R12", "L12", "LL12"] {
+                let solver = Solver2022_22::try_from(empty_cube(net, path).as_str()).unwrap();
+                let (x, y, direction) = solver.start().unwrap();
+                let direction =
+                    (direction + path.matches('R').count() + 4 - path.matches('L').count()) % 4;
+                assert_eq!(
+                    solver.solve_second_part().unwrap(),
+                    1000 * (y + 1) + 4 * (x + 1) + direction
+                );
+            }
+        }
     }
 }
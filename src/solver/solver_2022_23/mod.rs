@@ -0,0 +1,183 @@
+use super::{parse, ParseError, SolveError, Solver};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hasher};
+
+type Position = (i32, i32);
+
+/// Multiplicative hashing of the coordinates, as SipHash dominates the rounds otherwise.
+#[derive(Default)]
+struct PositionHasher(u64);
+
+impl Hasher for PositionHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        bytes.iter().for_each(|byte| self.write_u32(*byte as u32));
+    }
+
+    fn write_i32(&mut self, value: i32) {
+        self.write_u32(value as u32);
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.0 = (self.0.rotate_left(5) ^ value as u64).wrapping_mul(0x517cc1b727220a95);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+type PositionState = BuildHasherDefault<PositionHasher>;
+type Elves = HashSet<Position, PositionState>;
+
+const NEIGHBOURS: [Position; 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+// The cells an elf looks at before moving north, south, west and east, the move being the middle.
+const DIRECTIONS: [[Position; 3]; 4] = [
+    [(-1, -1), (-1, 0), (-1, 1)],
+    [(1, -1), (1, 0), (1, 1)],
+    [(-1, -1), (0, -1), (1, -1)],
+    [(-1, 1), (0, 1), (1, 1)],
+];
+
+fn add(position: Position, offset: Position) -> Position {
+    (position.0 + offset.0, position.1 + offset.1)
+}
+
+/// Plays one round and tells whether any elf moved.
+fn spread(elves: &mut Elves, round: usize) -> bool {
+    // Each proposed position with the elf that proposed it, or `None` once two elves did.
+    let mut proposals: HashMap<Position, Option<Position>, PositionState> = HashMap::default();
+    for &elf in elves.iter() {
+        let is_free = |offset: &Position| !elves.contains(&add(elf, *offset));
+        if NEIGHBOURS.iter().all(is_free) {
+            continue;
+        }
+        let direction = (0..4)
+            .map(|turn| DIRECTIONS[(round + turn) % 4])
+            .find(|cells| cells.iter().all(is_free));
+        if let Some(cells) = direction {
+            match proposals.entry(add(elf, cells[1])) {
+                Entry::Vacant(entry) => {
+                    entry.insert(Some(elf));
+                }
+                Entry::Occupied(mut entry) => {
+                    entry.insert(None);
+                }
+            }
+        }
+    }
+    let mut moved = false;
+    for (target, elf) in proposals {
+        if let Some(elf) = elf {
+            elves.remove(&elf);
+            elves.insert(target);
+            moved = true;
+        }
+    }
+    moved
+}
+
+fn count_empty_ground(elves: &Elves) -> usize {
+    let rows = elves.iter().map(|elf| elf.0);
+    let columns = elves.iter().map(|elf| elf.1);
+    let height = rows.clone().max().unwrap_or(0) - rows.min().unwrap_or(0) + 1;
+    let width = columns.clone().max().unwrap_or(0) - columns.min().unwrap_or(0) + 1;
+    (height * width) as usize - elves.len()
+}
+
+pub struct Solver2022_23 {
+    elves: Elves,
+}
+
+impl Default for Solver2022_23 {
+    fn default() -> Self {
+        Self::try_from(include_str!("input.txt")).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Solver2022_23 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let mut elves = Elves::default();
+        for (row, line) in input.split("\n").enumerate() {
+            parse::allowed_chars(input, line, "#.")?;
+            for (column, tile) in line.chars().enumerate() {
+                if tile == '#' {
+                    elves.insert((row as i32, column as i32));
+                }
+            }
+        }
+        Ok(Self { elves })
+    }
+}
+
+impl Solver<usize, usize> for Solver2022_23 {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        let mut elves = self.elves.clone();
+        for round in 0..10 {
+            spread(&mut elves, round);
+        }
+        Ok(count_empty_ground(&elves))
+    }
+
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        let mut elves = self.elves.clone();
+        let mut round = 0;
+        while spread(&mut elves, round) {
+            round += 1;
+        }
+        Ok(round + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = "\
+        ....#..\n\
+        ..###.#\n\
+        #...#.#\n\
+        .#...##\n\
+        #.###..\n\
+        ##.#.##\n\
+        .#..#..";
+
+    #[test]
+    fn should_spread_small_example() {
+        let mut elves = Solver2022_23::try_from(".....\n..##.\n..#..\n.....\n..##.\n.....")
+            .unwrap()
+            .elves;
+        for round in 0..3 {
+            spread(&mut elves, round);
+        }
+        assert_eq!(
+            elves,
+            Elves::from_iter([(0, 2), (1, 4), (2, 0), (3, 4), (5, 2)])
+        );
+        assert!(!spread(&mut elves, 3));
+    }
+
+    #[test]
+    fn should_solve_first_part() {
+        let solver = Solver2022_23::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 110);
+    }
+
+    #[test]
+    fn should_solve_second_part() {
+        let solver = Solver2022_23::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 20);
+    }
+}
@@ -0,0 +1,195 @@
+use super::{ParseError, SolveError, Solver};
+use std::collections::VecDeque;
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub struct Solver2022_24 {
+    // The tiles inside the walls, with the blizzards where they are at minute 0.
+    valley: Vec<Vec<char>>,
+    start_column: usize,
+    end_column: usize,
+}
+
+impl Default for Solver2022_24 {
+    fn default() -> Self {
+        Self::try_from(include_str!("input.txt")).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Solver2022_24 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let rows: Vec<&str> = input.split("\n").collect();
+        let width = rows[0].len();
+        if rows.len() < 3 || width < 3 {
+            return Err(ParseError::new(1, 1, "expected a valley inside walls"));
+        }
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != width {
+                return Err(ParseError::at(
+                    input,
+                    row,
+                    format!("expected {width} tiles"),
+                ));
+            }
+            let allowed = if i == 0 || i == rows.len() - 1 {
+                "#."
+            } else {
+                "#.<>^v"
+            };
+            if let Some((j, other)) = row
+                .chars()
+                .enumerate()
+                .find(|(j, c)| !allowed.contains(*c) || ((*j == 0 || *j == width - 1) && *c != '#'))
+            {
+                return Err(ParseError::new(
+                    i + 1,
+                    j + 1,
+                    format!("unexpected `{other}`"),
+                ));
+            }
+        }
+        let entrance = |row: &str| {
+            row.find('.')
+                .ok_or_else(|| ParseError::at(input, row, "expected an opening in the wall"))
+        };
+        Ok(Self {
+            start_column: entrance(rows[0])?,
+            end_column: entrance(rows[rows.len() - 1])?,
+            valley: rows[1..rows.len() - 1]
+                .iter()
+                .map(|row| row[1..width - 1].chars().collect())
+                .collect(),
+        })
+    }
+}
+
+impl Solver2022_24 {
+    fn height(&self) -> usize {
+        self.valley.len()
+    }
+
+    fn width(&self) -> usize {
+        self.valley[0].len()
+    }
+
+    // The blizzards are back where they started after this many minutes.
+    fn period(&self) -> usize {
+        self.height() * self.width() / gcd(self.height(), self.width())
+    }
+
+    // Looks for each kind of blizzard at the tile it would have started from to be here now.
+    fn is_blizzard(&self, (x, y): (usize, usize), minute: usize) -> bool {
+        let (width, height) = (self.width(), self.height());
+        self.valley[y][(x + width - minute % width) % width] == '>'
+            || self.valley[y][(x + minute) % width] == '<'
+            || self.valley[(y + height - minute % height) % height][x] == 'v'
+            || self.valley[(y + minute) % height][x] == '^'
+    }
+
+    // Positions are on the map with its walls, so the start is on row 0 and the end on the last.
+    fn is_free(&self, (x, y): (usize, usize), minute: usize) -> bool {
+        match y {
+            0 => x == self.start_column,
+            y if y == self.height() + 1 => x == self.end_column,
+            _ => x >= 1 && x <= self.width() && !self.is_blizzard((x - 1, y - 1), minute),
+        }
+    }
+
+    fn get_available_moves(&self, (x, y): (usize, usize), minute: usize) -> Vec<(usize, usize)> {
+        let mut moves = vec![(x, y), (x + 1, y), (x - 1, y), (x, y + 1)];
+        if y > 0 {
+            moves.push((x, y - 1));
+        }
+        moves
+            .into_iter()
+            .filter(|&(x, y)| y <= self.height() + 1 && self.is_free((x, y), minute))
+            .collect()
+    }
+
+    /// The minute of the earliest arrival at `end` when leaving `start` at `minute`.
+    fn cross(
+        &self,
+        start: (usize, usize),
+        end: (usize, usize),
+        minute: usize,
+    ) -> Result<usize, SolveError> {
+        let period = self.period();
+        let mut visited = vec![vec![vec![false; self.width() + 2]; self.height() + 2]; period];
+        let mut queue = VecDeque::from([(start, minute)]);
+        visited[minute % period][start.1][start.0] = true;
+        while let Some((position, minute)) = queue.pop_front() {
+            if position == end {
+                return Ok(minute);
+            }
+            for (x, y) in self.get_available_moves(position, minute + 1) {
+                let seen = &mut visited[(minute + 1) % period][y][x];
+                if !*seen {
+                    *seen = true;
+                    queue.push_back(((x, y), minute + 1));
+                }
+            }
+        }
+        Err(SolveError::NoSolution)
+    }
+
+    fn entrances(&self) -> ((usize, usize), (usize, usize)) {
+        ((self.start_column, 0), (self.end_column, self.height() + 1))
+    }
+}
+
+impl Solver<usize, usize> for Solver2022_24 {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        let (start, end) = self.entrances();
+        self.cross(start, end, 0)
+    }
+
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        let (start, end) = self.entrances();
+        let there = self.cross(start, end, 0)?;
+        let back = self.cross(end, start, there)?;
+        self.cross(start, end, back)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = "\
+        #.######\n\
+        #>>.<^<#\n\
+        #.<..<<#\n\
+        #>v.><>#\n\
+        #<^v^^>#\n\
+        ######.#";
+
+    #[test]
+    fn should_move_blizzards() {
+        let solver = Solver2022_24::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.period(), 12);
+        assert!(solver.is_blizzard((0, 0), 0));
+        assert!(!solver.is_blizzard((2, 0), 0));
+        assert!(solver.is_blizzard((2, 0), 1));
+        assert!(!solver.is_blizzard((0, 0), 1));
+    }
+
+    #[test]
+    fn should_solve_first_part() {
+        let solver = Solver2022_24::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 18);
+    }
+
+    #[test]
+    fn should_solve_second_part() {
+        let solver = Solver2022_24::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 54);
+    }
+}
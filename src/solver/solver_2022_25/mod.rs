@@ -0,0 +1,255 @@
+use super::{parse, ParseError, SolveError, Solver};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::iter::Sum;
+use std::ops::{Add, AddAssign};
+use std::str::FromStr;
+
+const DIGITS: &str = "=-012";
+
+/// A number in balanced base 5, written with the digits `=`, `-`, `0`, `1` and `2` for -2 to 2.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snafu {
+    // The least significant digit first, without leading zeros, so zero has no digits at all.
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    /// Parses `span`, a slice of `input`, so that errors point into the whole input.
+    fn parse(input: &str, span: &str) -> Result<Self, ParseError> {
+        if span.is_empty() {
+            return Err(ParseError::at(input, span, "expected a SNAFU number"));
+        }
+        parse::allowed_chars(input, span, DIGITS)?;
+        let mut snafu = Snafu {
+            digits: span
+                .chars()
+                .rev()
+                .map(|digit| DIGITS.find(digit).unwrap() as i8 - 2)
+                .collect(),
+        };
+        snafu.trim();
+        Ok(snafu)
+    }
+
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Snafu::parse(text, text)
+    }
+}
+
+impl Display for Snafu {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+        self.digits.iter().rev().try_for_each(|digit| {
+            let index = (digit + 2) as usize;
+            write!(f, "{}", &DIGITS[index..index + 1])
+        })
+    }
+}
+
+/// The error of converting a SNAFU number into an integer type that is too small to hold it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnafuOverflow {
+    bits: u32,
+}
+
+impl Display for SnafuOverflow {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the number does not fit in {} bits", self.bits)
+    }
+}
+
+impl Error for SnafuOverflow {}
+
+macro_rules! snafu_integers {
+    ($($integer:ty),*) => {
+        $(impl From<$integer> for Snafu {
+            fn from(mut value: $integer) -> Self {
+                let mut digits = Vec::new();
+                while value != 0 {
+                    let mut digit = value.rem_euclid(5);
+                    value = value.div_euclid(5);
+                    if digit > 2 {
+                        digit -= 5;
+                        value += 1;
+                    }
+                    digits.push(digit as i8);
+                }
+                Snafu { digits }
+            }
+        }
+
+        impl TryFrom<&Snafu> for $integer {
+            type Error = SnafuOverflow;
+
+            fn try_from(snafu: &Snafu) -> Result<Self, Self::Error> {
+                snafu
+                    .digits
+                    .iter()
+                    .rev()
+                    .try_fold(0 as $integer, |value, digit| {
+                        // A digit against the sign of the value is borrowed first, so that the
+                        // extremes of the type don't overflow on the way.
+                        let (value, digit) = match (value.signum(), *digit as $integer) {
+                            (-1, digit) if digit > 0 => (value + 1, digit - 5),
+                            (1, digit) if digit < 0 => (value - 1, digit + 5),
+                            (_, digit) => (value, digit),
+                        };
+                        value.checked_mul(5)?.checked_add(digit)
+                    })
+                    .ok_or(SnafuOverflow { bits: <$integer>::BITS })
+            }
+        })*
+    };
+}
+
+snafu_integers!(i64, i128);
+
+impl AddAssign<&Snafu> for Snafu {
+    fn add_assign(&mut self, other: &Snafu) {
+        if self.digits.len() < other.digits.len() {
+            self.digits.resize(other.digits.len(), 0);
+        }
+        let mut carry = 0;
+        for (i, digit) in self.digits.iter_mut().enumerate() {
+            let sum = *digit + other.digits.get(i).copied().unwrap_or(0) + carry;
+            (*digit, carry) = match sum {
+                3.. => (sum - 5, 1),
+                ..=-3 => (sum + 5, -1),
+                _ => (sum, 0),
+            };
+        }
+        if carry != 0 {
+            self.digits.push(carry);
+        }
+        self.trim();
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(mut self, other: Snafu) -> Snafu {
+        self += &other;
+        self
+    }
+}
+
+impl<'a> Sum<&'a Snafu> for Snafu {
+    fn sum<I: Iterator<Item = &'a Snafu>>(numbers: I) -> Snafu {
+        numbers.fold(Snafu::default(), |mut sum, number| {
+            sum += number;
+            sum
+        })
+    }
+}
+
+pub struct Solver2022_25 {
+    numbers: Vec<Snafu>,
+}
+
+impl Default for Solver2022_25 {
+    fn default() -> Self {
+        Self::try_from(include_str!("input.txt")).unwrap()
+    }
+}
+
+impl TryFrom<&str> for Solver2022_25 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            numbers: input
+                .split("\n")
+                .map(|line| Snafu::parse(input, line))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl Solver<String, usize> for Solver2022_25 {
+    fn solve_first_part(&self) -> Result<String, SolveError> {
+        Ok(self.numbers.iter().sum::<Snafu>().to_string())
+    }
+
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static EXAMPLE: &str = "\
+        1=-0-2\n\
+        12111\n\
+        2=0=\n\
+        21\n\
+        2=01\n\
+        111\n\
+        20012\n\
+        112\n\
+        1=-1=\n\
+        1-12\n\
+        12\n\
+        1=\n\
+        122";
+
+    #[test]
+    fn should_convert_snafu_numbers() {
+        for (value, text) in [
+            (0, "0"),
+            (3, "1="),
+            (-3, "-2"),
+            (2022, "1=11-2"),
+            (314159265, "1121-1110-1=0"),
+        ] {
+            let snafu: Snafu = text.parse().unwrap();
+            assert_eq!(snafu, Snafu::from(value));
+            assert_eq!(snafu.to_string(), text);
+            assert_eq!(i64::try_from(&snafu), Ok(value));
+        }
+        assert_eq!(
+            Snafu::from(i128::MAX).to_string().parse(),
+            Ok(Snafu::from(i128::MAX))
+        );
+        assert_eq!(i128::try_from(&Snafu::from(i128::MIN)), Ok(i128::MIN));
+        let overflow = i64::try_from(&Snafu::from(i128::from(i64::MAX) + 1)).unwrap_err();
+        assert_eq!(overflow.to_string(), "the number does not fit in 64 bits");
+    }
+
+    #[test]
+    fn should_reject_invalid_digits() {
+        assert_eq!(
+            "1=3".parse::<Snafu>(),
+            Err(ParseError::new(1, 3, "unexpected `3`"))
+        );
+        assert!("".parse::<Snafu>().is_err());
+    }
+
+    #[test]
+    fn should_add_snafu_numbers() {
+        for (a, b) in [(0, 0), (2, 1), (-7, 3), (12345, -678), (i64::MIN, i64::MAX)] {
+            assert_eq!(Snafu::from(a) + Snafu::from(b), Snafu::from(a + b));
+        }
+    }
+
+    #[test]
+    fn should_solve_first_part() {
+        let solver = Solver2022_25::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), "2=-1=0");
+    }
+}
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::HashMap;
 
 pub struct Solver2024_01 {
@@ -8,45 +8,49 @@ pub struct Solver2024_01 {
 
 impl Default for Solver2024_01 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_01 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2024_01 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let mut left_list = Vec::new();
         let mut right_list = Vec::new();
 
         for line in input.lines() {
-            let mut line = line.split_whitespace();
-            let left = line.next().unwrap().parse().unwrap();
-            let right = line.next().unwrap().parse().unwrap();
-            left_list.push(left);
-            right_list.push(right);
+            let mut numbers = line.split_whitespace();
+            let (Some(left), Some(right), None) = (numbers.next(), numbers.next(), numbers.next())
+            else {
+                return Err(ParseError::at(input, line, "expected two location IDs"));
+            };
+            left_list.push(parse::value(input, left)?);
+            right_list.push(parse::value(input, right)?);
         }
 
-        Self {
+        Ok(Self {
             left_list,
             right_list,
-        }
+        })
     }
 }
 
 impl Solver<i32, i32> for Solver2024_01 {
-    fn solve_first_part(&self) -> i32 {
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
         let mut left_list = self.left_list.clone();
         left_list.sort();
         let mut right_list = self.right_list.clone();
         right_list.sort();
 
-        left_list
+        Ok(left_list
             .iter()
             .enumerate()
             .map(|(index, left)| (left - right_list[index]).abs())
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> i32 {
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
         let count_map = self
             .right_list
             .iter()
@@ -57,10 +61,11 @@ impl Solver<i32, i32> for Solver2024_01 {
                 map
             });
 
-        self.left_list
+        Ok(self
+            .left_list
             .iter()
             .map(|left| count_map.get(left).unwrap_or(&0) * left)
-            .sum()
+            .sum())
     }
 }
 
@@ -71,13 +76,13 @@ mod tests {
 
     #[test]
     fn solve_first_part() {
-        let solver = Solver2024_01::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 11);
+        let solver = Solver2024_01::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 11);
     }
 
     #[test]
     fn solve_second_part() {
-        let solver = Solver2024_01::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 31);
+        let solver = Solver2024_01::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 31);
     }
 }
@@ -1,6 +1,10 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 fn is_safe_list(list: &[i32]) -> bool {
+    // Removing a level can leave a single one, which is safe on its own.
+    if list.len() < 2 {
+        return true;
+    }
     let is_descending = list[0] < list[1];
     for i in 1..list.len() {
         let current = list[i];
@@ -54,34 +58,42 @@ pub struct Solver2024_02 {
 
 impl Default for Solver2024_02 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_02 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2024_02 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let lists = input
             .lines()
             .map(|line| {
-                line.split_whitespace()
-                    .map(|x| x.parse().unwrap())
-                    .collect()
+                let list: Vec<i32> = line
+                    .split_whitespace()
+                    .map(|x| parse::value(input, x))
+                    .collect::<Result<_, _>>()?;
+                if list.len() < 2 {
+                    return Err(ParseError::at(input, line, "expected two levels or more"));
+                }
+                Ok(list)
             })
-            .collect();
-        Self { lists }
+            .collect::<Result<_, _>>()?;
+        Ok(Self { lists })
     }
 }
 
 impl Solver<i32, i32> for Solver2024_02 {
-    fn solve_first_part(&self) -> i32 {
-        self.lists.iter().filter(|list| is_safe_list(list)).count() as i32
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        Ok(self.lists.iter().filter(|list| is_safe_list(list)).count() as i32)
     }
 
-    fn solve_second_part(&self) -> i32 {
-        self.lists
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .lists
             .iter()
             .filter(|list| is_safe_list_with_a_removed_level(list))
-            .count() as i32
+            .count() as i32)
     }
 }
 
@@ -123,13 +135,21 @@ mod tests {
 
     #[test]
     fn solve_first_part() {
-        let solver = Solver2024_02::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 2);
+        let solver = Solver2024_02::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2);
     }
 
     #[test]
     fn solve_second_part() {
-        let solver = Solver2024_02::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 4);
+        let solver = Solver2024_02::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 4);
+    }
+
+    #[test]
+    fn reject_report_with_single_level() {
+        assert_eq!(
+            Solver2024_02::try_from("1 2\n3").err(),
+            Some(ParseError::new(2, 1, "expected two levels or more"))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::borrow::Cow;
 
 use regex::Regex;
@@ -15,39 +15,43 @@ impl Default for Solver2024_03<'static> {
     }
 }
 
-impl<'a> From<&'a str> for Solver2024_03<'a> {
-    fn from(input: &'a str) -> Self {
-        Self {
+impl<'a> TryFrom<&'a str> for Solver2024_03<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
             input: Cow::Borrowed(input),
-        }
+        })
     }
 }
 
 impl<'a> Solver<i32, i32> for Solver2024_03<'a> {
-    fn solve_first_part(&self) -> i32 {
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
         let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
-        re.captures_iter(&self.input)
+        Ok(re
+            .captures_iter(&self.input)
             .map(|captures| {
                 let a: i32 = captures[1].parse().unwrap();
                 let b: i32 = captures[2].parse().unwrap();
                 a * b
             })
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> i32 {
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
         let input = self.input.replace("\n", "");
         let input = format!("{input}do()");
         let re = Regex::new(r"(don't\(\)).*?(do\(\))").unwrap();
         let input = re.replace_all(&input, "").to_string();
         let re = Regex::new(r"mul\((\d+),(\d+)\)").unwrap();
-        re.captures_iter(&input)
+        Ok(re
+            .captures_iter(&input)
             .map(|captures| {
                 let a: i32 = captures[1].parse().unwrap();
                 let b: i32 = captures[2].parse().unwrap();
                 a * b
             })
-            .sum()
+            .sum())
     }
 }
 
@@ -57,17 +61,19 @@ mod tests {
 
     #[test]
     fn solve_first_part() {
-        let solver = Solver2024_03::from(
+        let solver = Solver2024_03::try_from(
             "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))",
-        );
-        assert_eq!(solver.solve_first_part(), 161);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 161);
     }
 
     #[test]
     fn solve_second_part() {
-        let solver = Solver2024_03::from(
+        let solver = Solver2024_03::try_from(
             "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))",
-        );
-        assert_eq!(solver.solve_second_part(), 48);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 48);
     }
 }
@@ -1,23 +1,36 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 
 pub struct Solver2024_04 {
     matrix: Vec<Vec<char>>,
 }
 
-impl From<&str> for Solver2024_04 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2024_04 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::end_of_input(input, "a letter"));
+        }
+        if let Some(line) = input.lines().find(|line| line.chars().count() != width) {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected {width} letters"),
+            ));
+        }
+        Ok(Self {
             matrix: input
                 .lines()
                 .map(|line| line.chars().collect::<Vec<char>>())
                 .collect(),
-        }
+        })
     }
 }
 
 impl Default for Solver2024_04 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
@@ -102,7 +115,7 @@ impl Solver2024_04 {
 }
 
 impl Solver<i32, i32> for Solver2024_04 {
-    fn solve_first_part(&self) -> i32 {
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
         let height = self.matrix.len();
         let width = self.matrix[0].len();
 
@@ -116,10 +129,10 @@ impl Solver<i32, i32> for Solver2024_04 {
                 }
             }
         }
-        result
+        Ok(result)
     }
 
-    fn solve_second_part(&self) -> i32 {
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
         let height = self.matrix.len();
         let width = self.matrix[0].len();
 
@@ -131,7 +144,7 @@ impl Solver<i32, i32> for Solver2024_04 {
                 }
             }
         }
-        result
+        Ok(result)
     }
 }
 
@@ -182,11 +195,39 @@ MXMXAXMASX";
 
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_04::from(EXAMPLE).solve_first_part(), 18);
+        assert_eq!(
+            Solver2024_04::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            18
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_04::from(EXAMPLE).solve_second_part(), 9);
+        assert_eq!(
+            Solver2024_04::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            9
+        );
+    }
+
+    #[test]
+    fn reject_empty_input() {
+        assert_eq!(
+            Solver2024_04::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a letter, found end of input"
+            ))
+        );
+        assert_eq!(
+            Solver2024_04::try_from("XM\nA").err(),
+            Some(ParseError::new(2, 1, "expected 2 letters"))
+        );
     }
 }
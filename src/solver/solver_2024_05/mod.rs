@@ -1,7 +1,7 @@
 use std::cmp::Ordering;
 use std::collections::{HashMap, HashSet};
 
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 pub struct Solver2024_05 {
     precedence_map: HashMap<i32, HashSet<i32>>,
@@ -43,44 +43,53 @@ impl Solver2024_05 {
     }
 }
 
-impl From<&str> for Solver2024_05 {
-    fn from(value: &str) -> Self {
-        let parts: Vec<&str> = value.split("\n\n").collect();
-        let precedence_map = parts[0].lines().fold(HashMap::new(), |mut map, line| {
-            let nums: Vec<&str> = line.split('|').collect();
-            let x = nums[0].parse().unwrap();
-            let y = nums[1].parse().unwrap();
-            map.entry(x).or_insert_with(HashSet::new).insert(y);
-            map
-        });
-        let prints = parts[1]
+impl TryFrom<&str> for Solver2024_05 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (rules, updates) = value
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(value, "a list of updates"))?;
+        let mut precedence_map = HashMap::new();
+        for line in rules.lines() {
+            let (x, y) = line
+                .split_once('|')
+                .ok_or_else(|| ParseError::at(value, line, "expected `X|Y`"))?;
+            precedence_map
+                .entry(parse::value(value, x)?)
+                .or_insert_with(HashSet::new)
+                .insert(parse::value(value, y)?);
+        }
+        let prints = updates
             .lines()
-            .map(|line| line.split(',').map(|s| s.parse().unwrap()).collect())
-            .collect();
-        Self {
+            .map(|line| line.split(',').map(|s| parse::value(value, s)).collect())
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
             precedence_map,
             prints,
-        }
+        })
     }
 }
 
 impl Default for Solver2024_05 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        include_str!("input.txt").try_into().unwrap()
     }
 }
 
 impl Solver<i32, i32> for Solver2024_05 {
-    fn solve_first_part(&self) -> i32 {
-        self.prints
+    fn solve_first_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .prints
             .iter()
             .filter(|print| self.is_valid_print(print))
             .map(|print| *get_middle(&print).unwrap())
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> i32 {
-        self.prints
+    fn solve_second_part(&self) -> Result<i32, SolveError> {
+        Ok(self
+            .prints
             .iter()
             .filter(|print| !self.is_valid_print(print))
             .map(|print| {
@@ -88,7 +97,7 @@ impl Solver<i32, i32> for Solver2024_05 {
                 self.sort_print(&mut print);
                 *get_middle(&print).unwrap()
             })
-            .sum()
+            .sum())
     }
 }
 
@@ -127,11 +136,23 @@ mod tests {
 
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_05::from(EXAMPLE).solve_first_part(), 143);
+        assert_eq!(
+            Solver2024_05::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            143
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_05::from(EXAMPLE).solve_second_part(), 123);
+        assert_eq!(
+            Solver2024_05::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            123
+        );
     }
 }
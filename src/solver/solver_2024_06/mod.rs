@@ -1,6 +1,6 @@
 use std::collections::HashSet;
 
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 #[derive(Clone, Copy, Eq, PartialEq, Hash)]
 enum Direction {
@@ -91,27 +91,45 @@ impl Solver2024_06 {
     }
 }
 
-impl From<&str> for Solver2024_06 {
-    fn from(value: &str) -> Self {
-        Self {
-            map: value.lines().map(|line| line.chars().collect()).collect(),
+impl TryFrom<&str> for Solver2024_06 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(value, value, ".#^\n")?;
+        if !value.contains('^') {
+            return Err(ParseError::end_of_input(value, "a guard"));
         }
+        let width = value.lines().next().map_or(0, str::len);
+        if let Some(line) = value.lines().find(|line| line.len() != width) {
+            return Err(ParseError::at(
+                value,
+                line,
+                format!("expected {width} positions"),
+            ));
+        }
+        Ok(Self {
+            map: value.lines().map(|line| line.chars().collect()).collect(),
+        })
     }
 }
 
 impl Default for Solver2024_06 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        include_str!("input.txt").try_into().unwrap()
     }
 }
 
 impl Solver<usize, usize> for Solver2024_06 {
-    fn solve_first_part(&self) -> usize {
-        self.get_path(self.find_initial_position()).unwrap().len()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        self.get_path(self.find_initial_position())
+            .map(|path| path.len())
+            .ok_or(SolveError::NoSolution)
     }
 
-    fn solve_second_part(&self) -> usize {
-        let path = self.get_path(self.find_initial_position()).unwrap();
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        let path = self
+            .get_path(self.find_initial_position())
+            .ok_or(SolveError::NoSolution)?;
         let mut result = 0;
         for (x, y) in path.iter() {
             if self.map[*y][*x] != '.' {
@@ -126,7 +144,7 @@ impl Solver<usize, usize> for Solver2024_06 {
                 result += 1;
             }
         }
-        result
+        Ok(result)
     }
 }
 
@@ -143,14 +161,34 @@ mod tests {
 .#..^.....
 ........#.
 #.........
-......#...:";
+......#...";
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_06::from(EXAMPLE).solve_first_part(), 41);
+        assert_eq!(
+            Solver2024_06::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            41
+        );
+    }
+
+    #[test]
+    fn reject_ragged_map() {
+        assert_eq!(
+            Solver2024_06::try_from("...\n.^\n...").err(),
+            Some(ParseError::new(2, 1, "expected 3 positions"))
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_06::from(EXAMPLE).solve_second_part(), 6);
+        assert_eq!(
+            Solver2024_06::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            6
+        );
     }
 }
@@ -1,19 +1,21 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 use regex::Regex;
 
-fn parse_inputs(input: &str) -> Vec<(u64, Vec<u64>)> {
+fn parse_inputs(input: &str) -> Result<Vec<(u64, Vec<u64>)>, ParseError> {
     input
         .lines()
         .map(|line| {
-            let splits = line.split(": ").collect::<Vec<&str>>();
-            (
-                splits[0].parse().unwrap(),
-                splits[1]
+            let (test_value, numbers) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(input, line, "expected `TEST: NUMBERS`"))?;
+            Ok((
+                parse::value(input, test_value)?,
+                numbers
                     .split_whitespace()
-                    .map(|s| s.parse().unwrap())
-                    .collect(),
-            )
+                    .map(|s| parse::value(input, s))
+                    .collect::<Result<_, _>>()?,
+            ))
         })
         .collect()
 }
@@ -52,35 +54,39 @@ pub struct Solver2024_07 {
     equations: Vec<(u64, Vec<u64>)>,
 }
 
-impl From<&str> for Solver2024_07 {
-    fn from(value: &str) -> Self {
-        Self {
-            equations: parse_inputs(value),
-        }
+impl TryFrom<&str> for Solver2024_07 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            equations: parse_inputs(value)?,
+        })
     }
 }
 
 impl Default for Solver2024_07 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        include_str!("input.txt").try_into().unwrap()
     }
 }
 
 impl Solver<u64, u64> for Solver2024_07 {
-    fn solve_first_part(&self) -> u64 {
-        self.equations
+    fn solve_first_part(&self) -> Result<u64, SolveError> {
+        Ok(self
+            .equations
             .iter()
             .filter(|(left, right)| is_valid_equation(*left, right, false))
             .map(|equation| equation.0)
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> u64 {
-        self.equations
+    fn solve_second_part(&self) -> Result<u64, SolveError> {
+        Ok(self
+            .equations
             .iter()
             .filter(|(left, right)| is_valid_equation(*left, right, true))
             .map(|equation| equation.0)
-            .sum()
+            .sum())
     }
 }
 
@@ -100,7 +106,7 @@ mod tests {
 
     #[test]
     fn is_valid_equation() {
-        let equations = super::parse_inputs(EXAMPLE);
+        let equations = super::parse_inputs(EXAMPLE).unwrap();
         assert_eq!(
             super::is_valid_equation(equations[0].0, &equations[0].1, false),
             true
@@ -140,11 +146,23 @@ mod tests {
     }
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_07::from(EXAMPLE).solve_first_part(), 3749);
+        assert_eq!(
+            Solver2024_07::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            3749
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_07::from(EXAMPLE).solve_second_part(), 11387);
+        assert_eq!(
+            Solver2024_07::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            11387
+        );
     }
 }
@@ -1,20 +1,22 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::collections::{HashMap, HashSet};
 
 pub struct Solver2024_08 {
     map: Vec<Vec<char>>,
 }
 
-impl From<&str> for Solver2024_08 {
-    fn from(value: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2024_08 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
             map: value.lines().map(|line| line.chars().collect()).collect(),
-        }
+        })
     }
 }
 impl Default for Solver2024_08 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        include_str!("input.txt").try_into().unwrap()
     }
 }
 
@@ -58,7 +60,7 @@ impl Solver2024_08 {
 }
 
 impl Solver<usize, usize> for Solver2024_08 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let antennas = self.get_antennas();
         let mut anti_nodes = HashSet::new();
         for positions in antennas.values() {
@@ -75,10 +77,10 @@ impl Solver<usize, usize> for Solver2024_08 {
                 }
             }
         }
-        anti_nodes.len()
+        Ok(anti_nodes.len())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let antennas = self.get_antennas();
         let mut anti_nodes = HashSet::new();
         for positions in antennas.values() {
@@ -103,7 +105,7 @@ impl Solver<usize, usize> for Solver2024_08 {
                 }
             }
         }
-        anti_nodes.len()
+        Ok(anti_nodes.len())
     }
 }
 
@@ -125,11 +127,23 @@ mod tests {
 ............";
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_08::from(EXAMPLE).solve_first_part(), 14);
+        assert_eq!(
+            Solver2024_08::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            14
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_08::from(EXAMPLE).solve_second_part(), 34);
+        assert_eq!(
+            Solver2024_08::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            34
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 
 use std::collections::HashMap;
 
@@ -61,19 +61,29 @@ pub struct Solver2024_09 {
     files: Vec<File>,
 }
 
-impl From<&str> for Solver2024_09 {
-    fn from(value: &str) -> Self {
+impl TryFrom<&str> for Solver2024_09 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(value, value, "0123456789")?;
+        if value.is_empty() {
+            return Err(ParseError::end_of_input(value, "a disk map"));
+        }
+        // Files are at the even positions of the map, and compacting needs at least one block.
+        if value.chars().step_by(2).all(|size| size == '0') {
+            return Err(ParseError::new(1, 1, "the disk map has no file blocks"));
+        }
         let (files, empty_spaces) = get_files_and_empty_spaces(value);
-        Self {
+        Ok(Self {
             disk: parse_input(value),
             files,
             empty_spaces,
-        }
+        })
     }
 }
 impl Default for Solver2024_09 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        include_str!("input.txt").try_into().unwrap()
     }
 }
 
@@ -162,15 +172,15 @@ impl Solver2024_09 {
 }
 
 impl Solver<usize, usize> for Solver2024_09 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut cloned_self = self.clone();
         cloned_self.defragmentation();
-        cloned_self.checksum_from_disk()
+        Ok(cloned_self.checksum_from_disk())
     }
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut cloned_self = self.clone();
         cloned_self.defragmentation_with_moving_whole_files();
-        cloned_self.checksum_from_files()
+        Ok(cloned_self.checksum_from_files())
     }
 }
 
@@ -181,11 +191,39 @@ mod tests {
     static EXAMPLE: &str = "2333133121414131402";
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_09::from(EXAMPLE).solve_first_part(), 1928);
+        assert_eq!(
+            Solver2024_09::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            1928
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_09::from(EXAMPLE).solve_second_part(), 2858);
+        assert_eq!(
+            Solver2024_09::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            2858
+        );
+    }
+
+    #[test]
+    fn reject_disk_without_files() {
+        assert_eq!(
+            Solver2024_09::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a disk map, found end of input"
+            ))
+        );
+        assert_eq!(
+            Solver2024_09::try_from("09").err(),
+            Some(ParseError::new(1, 1, "the disk map has no file blocks"))
+        );
     }
 }
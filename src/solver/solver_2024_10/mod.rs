@@ -1,13 +1,16 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::HashSet;
 
 pub struct Solver2024_10 {
     map: Vec<Vec<u8>>,
 }
 
-impl From<&str> for Solver2024_10 {
-    fn from(value: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2024_10 {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(value, value, "0123456789\n")?;
+        Ok(Self {
             map: value
                 .lines()
                 .map(|line| {
@@ -16,12 +19,12 @@ impl From<&str> for Solver2024_10 {
                         .collect()
                 })
                 .collect(),
-        }
+        })
     }
 }
 impl Default for Solver2024_10 {
     fn default() -> Self {
-        include_str!("input.txt").into()
+        include_str!("input.txt").try_into().unwrap()
     }
 }
 
@@ -79,7 +82,7 @@ impl Solver2024_10 {
 }
 
 impl Solver<usize, usize> for Solver2024_10 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut result = 0;
         for y in 0..self.map.len() {
             for x in 0..self.map[y].len() {
@@ -88,17 +91,17 @@ impl Solver<usize, usize> for Solver2024_10 {
                 result += found.len();
             }
         }
-        result
+        Ok(result)
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut result = 0;
         for y in 0..self.map.len() {
             for x in 0..self.map[y].len() {
                 result += self.count_trails(x, y, 0);
             }
         }
-        result
+        Ok(result)
     }
 }
 
@@ -116,11 +119,23 @@ mod tests {
 10456732";
     #[test]
     fn solve_first_part() {
-        assert_eq!(Solver2024_10::from(EXAMPLE).solve_first_part(), 36);
+        assert_eq!(
+            Solver2024_10::try_from(EXAMPLE)
+                .unwrap()
+                .solve_first_part()
+                .unwrap(),
+            36
+        );
     }
 
     #[test]
     fn solve_second_part() {
-        assert_eq!(Solver2024_10::from(EXAMPLE).solve_second_part(), 81);
+        assert_eq!(
+            Solver2024_10::try_from(EXAMPLE)
+                .unwrap()
+                .solve_second_part()
+                .unwrap(),
+            81
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::HashMap;
 
 #[derive(Clone)]
@@ -8,18 +8,20 @@ pub struct Solver2024_11 {
 
 impl<'a> Default for Solver2024_11 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2024_11 {
-    fn from(input: &'a str) -> Self {
-        Self {
+impl<'a> TryFrom<&'a str> for Solver2024_11 {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
             rocks: input
                 .split_whitespace()
-                .map(|s| (s.parse().unwrap(), 1))
-                .collect(),
-        }
+                .map(|s| Ok((parse::value(input, s)?, 1)))
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
@@ -58,16 +60,16 @@ impl Solver2024_11 {
 }
 
 impl Solver<usize, usize> for Solver2024_11 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut muted = self.clone();
         muted.blink_n_times(25);
-        muted.rocks.values().sum()
+        Ok(muted.rocks.values().sum())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut muted = self.clone();
         muted.blink_n_times(75);
-        muted.rocks.values().sum()
+        Ok(muted.rocks.values().sum())
     }
 }
 
@@ -79,7 +81,7 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2024_11::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 55312);
+        let solver = Solver2024_11::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 55312);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 
 #[derive(Clone, Debug)]
 enum RegionWithPointer {
@@ -45,15 +45,28 @@ pub struct Solver2024_12 {
 
 impl Default for Solver2024_12 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_12 {
-    fn from(input: &str) -> Self {
-        Self {
-            input: input.lines().map(|line| line.chars().collect()).collect(),
+impl TryFrom<&str> for Solver2024_12 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let width = input.lines().next().map_or(0, |line| line.chars().count());
+        if width == 0 {
+            return Err(ParseError::end_of_input(input, "a garden plot"));
         }
+        if let Some(line) = input.lines().find(|line| line.chars().count() != width) {
+            return Err(ParseError::at(
+                input,
+                line,
+                format!("expected {width} garden plots"),
+            ));
+        }
+        Ok(Self {
+            input: input.lines().map(|line| line.chars().collect()).collect(),
+        })
     }
 }
 
@@ -219,24 +232,26 @@ impl Solver2024_12 {
 }
 
 impl Solver<usize, usize> for Solver2024_12 {
-    fn solve_first_part(&self) -> usize {
-        self.calculate_regions()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .calculate_regions()
             .iter()
             .filter_map(|r| match r {
                 RegionWithPointer::Pointer(_) => None,
                 RegionWithPointer::Actual(region) => Some(region.area * region.perimeter),
             })
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.calculate_regions()
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .calculate_regions()
             .iter()
             .filter_map(|r| match r {
                 RegionWithPointer::Pointer(_) => None,
                 RegionWithPointer::Actual(region) => Some(region.area * region.corners),
             })
-            .sum()
+            .sum())
     }
 }
 
@@ -257,38 +272,56 @@ MMMISSJEEE";
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2024_12::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 1930);
+        let solver = Solver2024_12::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 1930);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2024_12::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 1206);
+        let solver = Solver2024_12::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 1206);
     }
 
     #[test]
     fn should_solve_second_part_e_shape() {
-        let solver = Solver2024_12::from(
+        let solver = Solver2024_12::try_from(
             "EEEEE
 EXXXX
 EEEEE
 EXXXX
 EEEEE",
-        );
-        assert_eq!(solver.solve_second_part(), 236);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 236);
     }
 
     #[test]
     fn should_solve_second_part_two_inner_squares() {
-        let solver = Solver2024_12::from(
+        let solver = Solver2024_12::try_from(
             "AAAAAA
 AAABBA
 AAABBA
 ABBAAA
 ABBAAA
 AAAAAA",
+        )
+        .unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 368);
+    }
+
+    #[test]
+    fn should_reject_empty_garden() {
+        assert_eq!(
+            Solver2024_12::try_from("").err(),
+            Some(ParseError::new(
+                1,
+                1,
+                "expected a garden plot, found end of input"
+            ))
+        );
+        assert_eq!(
+            Solver2024_12::try_from("AB\nC").err(),
+            Some(ParseError::new(2, 1, "expected 2 garden plots"))
         );
-        assert_eq!(solver.solve_second_part(), 368);
     }
 }
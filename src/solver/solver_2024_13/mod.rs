@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
 
 #[derive(Debug)]
@@ -52,27 +52,33 @@ pub struct Solver2024_13 {
 
 impl Default for Solver2024_13 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_13 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2024_13 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let re = Regex::new(
-            r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)",
+            r"^Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)$",
         )
         .unwrap();
 
-        Self {
-            machines: re
-                .captures_iter(input)
-                .map(|cap| ClawMachine {
-                    button_a: (cap[1].parse().unwrap(), cap[2].parse().unwrap()),
-                    button_b: (cap[3].parse().unwrap(), cap[4].parse().unwrap()),
-                    prize: (cap[5].parse().unwrap(), cap[6].parse().unwrap()),
+        Ok(Self {
+            machines: input
+                .split("\n\n")
+                .map(|machine| {
+                    let cap = parse::captures(input, &re, machine)?;
+                    let value = |i| parse::value(input, cap.get(i).unwrap().as_str());
+                    Ok(ClawMachine {
+                        button_a: (value(1)?, value(2)?),
+                        button_b: (value(3)?, value(4)?),
+                        prize: (value(5)?, value(6)?),
+                    })
                 })
-                .collect(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+        })
     }
 }
 
@@ -90,11 +96,11 @@ impl Solver2024_13 {
 }
 
 impl Solver<usize, usize> for Solver2024_13 {
-    fn solve_first_part(&self) -> usize {
-        self.calculate_token_cost()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self.calculate_token_cost())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mutated_solver = Self {
             machines: self
                 .machines
@@ -108,7 +114,7 @@ impl Solver<usize, usize> for Solver2024_13 {
                 })
                 .collect(),
         };
-        mutated_solver.calculate_token_cost()
+        Ok(mutated_solver.calculate_token_cost())
     }
 }
 
@@ -136,7 +142,7 @@ Prize: X=18641, Y=10279\
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2024_13::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 480);
+        let solver = Solver2024_13::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 480);
     }
 }
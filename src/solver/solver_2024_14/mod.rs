@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -27,30 +27,40 @@ pub struct Solver2024_14 {
 
 impl Default for Solver2024_14 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_14 {
-    fn from(input: &str) -> Self {
-        let re_size = Regex::new(r"size=(\d+),(\d+)").unwrap();
-        let re_robot = Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap();
-        let size_captures = re_size.captures(input).unwrap();
-        Self {
-            robots: re_robot
-                .captures_iter(input)
-                .map(|cap| {
-                    (
-                        cap[1].parse().unwrap(),
-                        cap[2].parse().unwrap(),
-                        cap[3].parse().unwrap(),
-                        cap[4].parse().unwrap(),
-                    )
+impl TryFrom<&str> for Solver2024_14 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let re_size = Regex::new(r"^size=(\d+),(\d+)$").unwrap();
+        let re_robot = Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap();
+        let mut lines = input.lines();
+        let size_captures = parse::captures(
+            input,
+            &re_size,
+            lines
+                .next()
+                .ok_or_else(|| ParseError::end_of_input(input, "the size of the room"))?,
+        )?;
+        Ok(Self {
+            robots: lines
+                .map(|line| {
+                    let cap = parse::captures(input, &re_robot, line)?;
+                    let value = |i| cap.get(i).unwrap().as_str();
+                    Ok((
+                        parse::value(input, value(1))?,
+                        parse::value(input, value(2))?,
+                        parse::value(input, value(3))?,
+                        parse::value(input, value(4))?,
+                    ))
                 })
-                .collect(),
-            width: size_captures[1].parse().unwrap(),
-            height: size_captures[2].parse().unwrap(),
-        }
+                .collect::<Result<_, ParseError>>()?,
+            width: parse::value(input, size_captures.get(1).unwrap().as_str())?,
+            height: parse::value(input, size_captures.get(2).unwrap().as_str())?,
+        })
     }
 }
 
@@ -107,18 +117,18 @@ impl Solver2024_14 {
 }
 
 impl Solver<usize, usize> for Solver2024_14 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut mutated = self.clone();
         mutated.move_robots(100);
-        mutated.calculate_score()
+        Ok(mutated.calculate_score())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut mutated = self.clone();
         let mut i = 0;
         loop {
             if mutated.has_christmas_tree() {
-                return i;
+                return Ok(i);
             }
             mutated.move_robots(1);
             i += 1;
@@ -148,7 +158,7 @@ p=9,5 v=-3,-3\
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2024_14::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 12);
+        let solver = Solver2024_14::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 12);
     }
 }
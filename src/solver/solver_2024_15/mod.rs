@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::VecDeque;
 
 #[derive(PartialEq, Eq, Clone)]
@@ -47,44 +47,48 @@ pub struct Game {
     directions: Vec<Direction>,
 }
 
-impl From<&str> for Game {
-    fn from(input: &str) -> Self {
-        let parts = input.split("\n\n").collect::<Vec<_>>();
-        let map = parts[0]
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (map, moves) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a list of moves"))?;
+        parse::allowed_chars(input, map, ".@#O\n")?;
+        parse::allowed_chars(input, moves, "^>v<\n")?;
+        let player = map
+            .lines()
+            .enumerate()
+            .find_map(|(y, line)| Some(Coordinate(line.find(|c| c == '@')?, y)))
+            .ok_or_else(|| ParseError::at(input, map, "expected a robot"))?;
+        let map = map
             .lines()
             .map(|line| {
                 line.chars()
                     .map(|c| match c {
                         '.' | '@' => Cell::Empty,
                         '#' => Cell::Wall,
-                        'O' => Cell::Box,
-                        _ => unreachable!(),
+                        _ => Cell::Box,
                     })
                     .collect()
             })
             .collect();
 
-        let directions = parts[1]
+        let directions = moves
             .chars()
             .filter_map(|c| match c {
                 '^' => Some(Direction::Up),
                 '>' => Some(Direction::Right),
                 'v' => Some(Direction::Down),
                 '<' => Some(Direction::Left),
-                '\n' => None,
-                _ => unreachable!(),
+                _ => None,
             })
             .collect();
-        let player = parts[0]
-            .lines()
-            .enumerate()
-            .find_map(|(y, line)| Some(Coordinate(line.find(|c| c == '@')?, y)))
-            .unwrap();
-        Self {
+        Ok(Self {
             map,
             player,
             directions,
-        }
+        })
     }
 }
 
@@ -166,13 +170,15 @@ pub struct Solver2024_15 {
 
 impl Default for Solver2024_15 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_15 {
-    fn from(input: &str) -> Self {
-        let part1 = Game::from(input);
+impl TryFrom<&str> for Solver2024_15 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let part1 = Game::try_from(input)?;
         let mut part2 = part1.clone();
         part2.map = part2
             .map
@@ -187,21 +193,21 @@ impl From<&str> for Solver2024_15 {
             })
             .collect();
         part2.player.0 *= 2;
-        Self { part1, part2 }
+        Ok(Self { part1, part2 })
     }
 }
 
 impl Solver<usize, usize> for Solver2024_15 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut mutated = self.part1.clone();
         mutated.move_player();
-        mutated.calculate_score()
+        Ok(mutated.calculate_score())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut mutated = self.part2.clone();
         mutated.move_player();
-        mutated.calculate_score()
+        Ok(mutated.calculate_score())
     }
 }
 
@@ -248,25 +254,25 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^\
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2024_15::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 2028);
+        let solver = Solver2024_15::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2028);
     }
 
     #[test]
     fn should_solve_first_part_big() {
-        let solver = Solver2024_15::from(BIG_EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 10092);
+        let solver = Solver2024_15::try_from(BIG_EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 10092);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2024_15::from(BIG_EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 9021);
+        let solver = Solver2024_15::try_from(BIG_EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 9021);
     }
 
     #[test]
     fn should_solve_second_part_example_from_reddit() {
-        let solver = Solver2024_15::from(
+        let solver = Solver2024_15::try_from(
             "\
 ######
 #....#
@@ -280,7 +286,16 @@ v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^\
 
 <vv<<^^^\
 ",
-        );
-        assert_eq!(solver.solve_second_part(), 1216);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 1216);
+    }
+
+    #[test]
+    fn should_reject_unknown_tile() {
+        let error = Solver2024_15::try_from(EXAMPLE.replacen("@", "x", 1).as_str())
+            .err()
+            .unwrap();
+        assert_eq!(error, ParseError::new(3, 3, "unexpected `x`"));
     }
 }
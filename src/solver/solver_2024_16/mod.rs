@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::cmp::PartialEq;
 use std::collections::{HashMap, HashSet};
 
@@ -55,46 +55,37 @@ pub struct Solver2024_16 {
 
 impl Default for Solver2024_16 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_16 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2024_16 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, ".#SE\n")?;
+        let find = |tile| {
+            input
+                .lines()
+                .enumerate()
+                .find_map(|(y, line)| Some(Coordinate(line.find(tile)?, y)))
+                .ok_or_else(|| ParseError::end_of_input(input, &format!("a `{tile}` tile")))
+        };
+        Ok(Self {
             maze: input
                 .lines()
                 .map(|line| {
                     line.chars()
                         .map(|c| match c {
-                            '.' | 'S' | 'E' => Cell::Empty,
                             '#' => Cell::Wall,
-                            _ => unreachable!(),
+                            _ => Cell::Empty,
                         })
                         .collect()
                 })
                 .collect(),
-            start: input
-                .lines()
-                .enumerate()
-                .find_map(|(y, line)| {
-                    line.chars().enumerate().find_map(|(x, c)| match c {
-                        'S' => Some(Coordinate(x, y)),
-                        _ => None,
-                    })
-                })
-                .unwrap(),
-            end: input
-                .lines()
-                .enumerate()
-                .find_map(|(y, line)| {
-                    line.chars().enumerate().find_map(|(x, c)| match c {
-                        'E' => Some(Coordinate(x, y)),
-                        _ => None,
-                    })
-                })
-                .unwrap(),
-        }
+            start: find('S')?,
+            end: find('E')?,
+        })
     }
 }
 
@@ -240,12 +231,12 @@ impl Solver2024_16 {
 }
 
 impl Solver<usize, usize> for Solver2024_16 {
-    fn solve_first_part(&self) -> usize {
-        self.find_shortest_path().unwrap()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        self.find_shortest_path().ok_or(SolveError::NoSolution)
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.find_best_seats().unwrap()
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        self.find_best_seats().ok_or(SolveError::NoSolution)
     }
 }
 
@@ -273,13 +264,20 @@ mod tests {
 
     #[test]
     fn should_solve_first_part() {
-        let solver = Solver2024_16::from(EXAMPLE);
+        let solver = Solver2024_16::try_from(EXAMPLE).unwrap();
         assert_eq!(solver.find_shortest_path().unwrap(), 7036);
     }
 
     #[test]
     fn should_solve_second_part() {
-        let solver = Solver2024_16::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 45);
+        let solver = Solver2024_16::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 45);
+    }
+
+    #[test]
+    fn should_report_walled_off_end() {
+        let solver = Solver2024_16::try_from("#####\n#S#E#\n#####").unwrap();
+        assert_eq!(solver.solve_first_part(), Err(SolveError::NoSolution));
+        assert_eq!(solver.solve_second_part(), Err(SolveError::NoSolution));
     }
 }
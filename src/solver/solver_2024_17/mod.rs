@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
 
 #[derive(Clone)]
@@ -13,53 +13,37 @@ pub struct Solver2024_17 {
 
 impl Default for Solver2024_17 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_17 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2024_17 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
         let re_register_a = Regex::new(r"Register A: (\d+)").unwrap();
         let re_register_b = Regex::new(r"Register B: (\d+)").unwrap();
         let re_register_c = Regex::new(r"Register C: (\d+)").unwrap();
-        let re_program = Regex::new(r"Program: (.*)").unwrap();
-        Self {
-            register_a: re_register_a
-                .captures(input)
-                .unwrap()
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse()
-                .unwrap(),
-            register_b: re_register_b
-                .captures(input)
-                .unwrap()
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse()
-                .unwrap(),
-            register_c: re_register_c
-                .captures(input)
-                .unwrap()
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse()
-                .unwrap(),
-            program: re_program
-                .captures(input)
-                .unwrap()
-                .get(1)
-                .unwrap()
-                .as_str()
+        let re_program = Regex::new(r"Program: ([\d,]*)").unwrap();
+        let register = |re| -> Result<usize, ParseError> {
+            let captures = parse::captures(input, re, input)?;
+            parse::value(input, captures.get(1).unwrap().as_str())
+        };
+        let program = parse::captures(input, &re_program, input)?
+            .get(1)
+            .unwrap()
+            .as_str();
+        Ok(Self {
+            register_a: register(&re_register_a)?,
+            register_b: register(&re_register_b)?,
+            register_c: register(&re_register_c)?,
+            program: program
                 .split(",")
-                .map(|s| s.parse().unwrap())
-                .collect(),
+                .map(|s| parse::value(input, s))
+                .collect::<Result<_, _>>()?,
             pointer: 0,
             output: vec![],
-        }
+        })
     }
 }
 
@@ -123,19 +107,14 @@ impl Solver2024_17 {
     }
 }
 
-impl Solver<String, usize> for Solver2024_17 {
-    fn solve_first_part(&self) -> String {
+impl Solver<Vec<usize>, usize> for Solver2024_17 {
+    fn solve_first_part(&self) -> Result<Vec<usize>, SolveError> {
         let mut mutated = self.clone();
         mutated.run();
-        mutated
-            .output
-            .iter()
-            .map(|v| v.to_string())
-            .collect::<Vec<_>>()
-            .join(",")
+        Ok(mutated.output)
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let mut candidates: Vec<usize> = (0..8).collect();
         let mut next_candidates: Vec<usize> = vec![];
         for i in (0..self.program.len()).rev() {
@@ -156,7 +135,7 @@ impl Solver<String, usize> for Solver2024_17 {
             next_candidates = vec![];
         }
 
-        candidates.into_iter().min().unwrap()
+        candidates.into_iter().min().ok_or(SolveError::NoSolution)
     }
 }
 
@@ -166,7 +145,7 @@ mod test {
 
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_17::from(
+        let solver = Solver2024_17::try_from(
             "\
 Register A: 729
 Register B: 0
@@ -174,14 +153,18 @@ Register C: 0
 
 Program: 0,1,5,4,3,0\
 ",
-        );
+        )
+        .unwrap();
 
-        assert_eq!(solver.solve_first_part(), "4,6,3,5,6,3,5,2,1,0");
+        assert_eq!(
+            solver.solve_first_part().unwrap(),
+            [4, 6, 3, 5, 6, 3, 5, 2, 1, 0]
+        );
     }
 
     #[test]
     fn test_solve_second_part() {
-        let solver = Solver2024_17::from(
+        let solver = Solver2024_17::try_from(
             "\
 Register A: 2024
 Register B: 0
@@ -189,7 +172,24 @@ Register C: 0
 
 Program: 0,3,5,4,3,0\
 ",
-        );
-        assert_eq!(solver.solve_second_part(), 117440);
+        )
+        .unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 117440);
+    }
+
+    #[test]
+    fn test_invalid_register() {
+        let error = Solver2024_17::try_from(
+            "\
+Register A: 2024
+Register C: 0
+
+Program: 0,3,5,4,3,0\
+",
+        )
+        .err()
+        .unwrap();
+        assert_eq!(error.line, 1);
+        assert!(error.message.contains("Register B"));
     }
 }
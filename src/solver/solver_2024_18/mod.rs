@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::{HashMap, HashSet, VecDeque};
 
 type Coordinate = (usize, usize);
@@ -37,30 +37,42 @@ pub struct Solver2024_18 {
 
 impl Default for Solver2024_18 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_18 {
-    fn from(input: &str) -> Self {
-        let parts: Vec<&str> = input.split("\n\n").collect();
-        let first_part: Vec<usize> = parts[0]
+impl TryFrom<&str> for Solver2024_18 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (first_part, walls) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a list of walls"))?;
+        let first_part: Vec<usize> = first_part
             .split(",")
-            .map(|num| num.parse().unwrap())
-            .collect();
-        Self {
-            width: first_part[0],
-            height: first_part[1],
-            number_of_walls: first_part[2],
-            walls: parts[1]
+            .map(|num| parse::value(input, num))
+            .collect::<Result<_, _>>()?;
+        let [width, height, number_of_walls] = first_part[..] else {
+            return Err(ParseError::new(
+                1,
+                1,
+                "expected `width,height,number_of_walls`",
+            ));
+        };
+        Ok(Self {
+            width,
+            height,
+            number_of_walls,
+            walls: walls
                 .lines()
-                .map(|line| {
-                    let nums: Vec<usize> =
-                        line.split(",").map(|num| num.parse().unwrap()).collect();
-                    (nums[0], nums[1])
+                .map(|line| -> Result<_, ParseError> {
+                    let (x, y) = line
+                        .split_once(",")
+                        .ok_or_else(|| ParseError::at(input, line, "expected `x,y`"))?;
+                    Ok((parse::value(input, x)?, parse::value(input, y)?))
                 })
-                .collect(),
-        }
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -199,14 +211,15 @@ impl Solver2024_18 {
 }
 
 impl Solver<usize, String> for Solver2024_18 {
-    fn solve_first_part(&self) -> usize {
-        self.find_shortest_path(self.number_of_walls).unwrap()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        self.find_shortest_path(self.number_of_walls)
+            .ok_or(SolveError::NoSolution)
     }
 
-    fn solve_second_part(&self) -> String {
-        let index = self.find_first_blocker().unwrap();
+    fn solve_second_part(&self) -> Result<String, SolveError> {
+        let index = self.find_first_blocker().ok_or(SolveError::NoSolution)?;
         let (x, y) = self.walls[index];
-        format!("{},{}", x, y)
+        Ok(format!("{},{}", x, y))
     }
 }
 
@@ -245,14 +258,14 @@ mod test {
 ";
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_18::from(EXAMPLE);
+        let solver = Solver2024_18::try_from(EXAMPLE).unwrap();
 
-        assert_eq!(solver.solve_first_part(), 22);
+        assert_eq!(solver.solve_first_part().unwrap(), 22);
     }
 
     #[test]
     fn test_solve_second_part() {
-        let solver = Solver2024_18::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), "6,1");
+        let solver = Solver2024_18::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), "6,1");
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::borrow::Cow;
 use std::collections::HashMap;
 
@@ -10,17 +10,21 @@ pub struct Solver2024_19<'a> {
 
 impl Default for Solver2024_19<'static> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2024_19<'a> {
-    fn from(input: &'a str) -> Self {
-        let parts: Vec<&str> = input.split("\n\n").collect();
-        Self {
-            patterns: parts[0].split(", ").map(|s| Cow::Borrowed(s)).collect(),
-            designs: parts[1].lines().map(|s| Cow::Borrowed(s)).collect(),
-        }
+impl<'a> TryFrom<&'a str> for Solver2024_19<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let (patterns, designs) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a list of designs"))?;
+        Ok(Self {
+            patterns: patterns.split(", ").map(|s| Cow::Borrowed(s)).collect(),
+            designs: designs.lines().map(|s| Cow::Borrowed(s)).collect(),
+        })
     }
 }
 
@@ -54,15 +58,16 @@ impl<'a> Solver2024_19<'a> {
 }
 
 impl<'a> Solver<usize, usize> for Solver2024_19<'a> {
-    fn solve_first_part(&self) -> usize {
-        self.designs.iter().filter(|s| self.is_match(s)).count()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self.designs.iter().filter(|s| self.is_match(s)).count())
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.designs
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .designs
             .iter()
             .map(|s| self.count_matches(s, &mut HashMap::new()))
-            .sum()
+            .sum())
     }
 }
 
@@ -84,14 +89,14 @@ bbrgwb\
 ";
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_19::from(EXAMPLE);
+        let solver = Solver2024_19::try_from(EXAMPLE).unwrap();
 
-        assert_eq!(solver.solve_first_part(), 6);
+        assert_eq!(solver.solve_first_part().unwrap(), 6);
     }
 
     #[test]
     fn test_solve_second_part() {
-        let solver = Solver2024_19::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 16);
+        let solver = Solver2024_19::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 16);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::{HashMap, VecDeque};
 
 type Coordinate = (usize, usize);
@@ -36,13 +36,16 @@ pub struct Solver2024_20 {
 
 impl Default for Solver2024_20 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_20 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2024_20 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, ".#SE\n")?;
+        Ok(Self {
             map: input
                 .lines()
                 .map(|line| line.chars().map(|c| c == '#').collect())
@@ -55,9 +58,9 @@ impl From<&str> for Solver2024_20 {
                         .enumerate()
                         .find_map(|(x, c)| (c == 'S').then(|| (x, y)))
                 })
-                .unwrap(),
+                .ok_or_else(|| ParseError::end_of_input(input, "a `S` tile"))?,
             cheat_threshold: 100,
-        }
+        })
     }
 }
 
@@ -131,26 +134,26 @@ impl Solver2024_20 {
 }
 
 impl Solver<usize, usize> for Solver2024_20 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let distances = self.get_distances();
-        (0..self.map.len())
+        Ok((0..self.map.len())
             .map(|y| {
                 (0..self.map[0].len())
                     .map(|x| self.get_shortcuts((x, y), 2, &distances))
                     .sum::<usize>()
             })
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let distances = self.get_distances();
-        (0..self.map.len())
+        Ok((0..self.map.len())
             .map(|y| {
                 (0..self.map[0].len())
                     .map(|x| self.get_shortcuts((x, y), 20, &distances))
                     .sum::<usize>()
             })
-            .sum()
+            .sum())
     }
 }
 
@@ -161,15 +164,15 @@ mod test {
     static EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn test_solve_first_part() {
-        let mut solver = Solver2024_20::from(EXAMPLE);
+        let mut solver = Solver2024_20::try_from(EXAMPLE).unwrap();
         solver.cheat_threshold = 1;
-        assert_eq!(solver.solve_first_part(), 44);
+        assert_eq!(solver.solve_first_part().unwrap(), 44);
     }
 
     #[test]
     fn test_solve_second_part() {
-        let mut solver = Solver2024_20::from(EXAMPLE);
+        let mut solver = Solver2024_20::try_from(EXAMPLE).unwrap();
         solver.cheat_threshold = 50;
-        assert_eq!(solver.solve_second_part(), 285);
+        assert_eq!(solver.solve_second_part().unwrap(), 285);
     }
 }
@@ -1,4 +1,4 @@
-use crate::solver::Solver;
+use crate::solver::{parse, ParseError, SolveError, Solver};
 use std::collections::HashMap;
 use std::fmt::Display;
 use std::ops::Sub;
@@ -207,18 +207,21 @@ pub struct Solver2024_21 {
 
 impl Default for Solver2024_21 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_21 {
-    fn from(input: &str) -> Self {
-        Self {
+impl TryFrom<&str> for Solver2024_21 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, "0123456789A\n")?;
+        Ok(Self {
             door_codes: input
                 .lines()
                 .map(|line| line.chars().map(NumericKeyPadButton::from).collect())
                 .collect(),
-        }
+        })
     }
 }
 
@@ -306,18 +309,20 @@ fn convert_dor_code_to_usize(code: &Vec<NumericKeyPadButton>) -> usize {
 }
 
 impl Solver<usize, usize> for Solver2024_21 {
-    fn solve_first_part(&self) -> usize {
-        self.door_codes
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .door_codes
             .iter()
             .map(|door_code| get_complexity(door_code, 2))
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> usize {
-        self.door_codes
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .door_codes
             .iter()
             .map(|door_code| get_complexity(door_code, 25))
-            .sum()
+            .sum())
     }
 }
 
@@ -328,7 +333,7 @@ mod test {
     static EXAMPLE: &str = include_str!("example.txt");
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_21::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 126384);
+        let solver = Solver2024_21::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 126384);
     }
 }
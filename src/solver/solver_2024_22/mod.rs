@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use std::collections::HashMap;
 
 pub struct Solver2024_22 {
@@ -7,15 +7,20 @@ pub struct Solver2024_22 {
 
 impl Default for Solver2024_22 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_22 {
-    fn from(input: &str) -> Self {
-        Self {
-            secret_numbers: input.lines().map(|line| line.parse().unwrap()).collect(),
-        }
+impl TryFrom<&str> for Solver2024_22 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        Ok(Self {
+            secret_numbers: input
+                .lines()
+                .map(|line| parse::value(input, line))
+                .collect::<Result<_, _>>()?,
+        })
     }
 }
 
@@ -70,21 +75,26 @@ fn get_price_map_for_sequences(
 }
 
 impl Solver<usize, usize> for Solver2024_22 {
-    fn solve_first_part(&self) -> usize {
-        self.secret_numbers
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self
+            .secret_numbers
             .iter()
             .map(|secret_number| get_nth_secret_number(*secret_number, 2000))
-            .sum()
+            .sum())
     }
 
-    fn solve_second_part(&self) -> usize {
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
         let prices_and_diffs: Vec<Vec<(usize, isize)>> = self
             .secret_numbers
             .iter()
             .map(|secret_number| get_prices_and_diffs(secret_number, 2000))
             .collect();
         let price_map = get_price_map_for_sequences(&prices_and_diffs, 4);
-        price_map.iter().map(|(_, &price)| price).max().unwrap()
+        price_map
+            .iter()
+            .map(|(_, &price)| price)
+            .max()
+            .ok_or(SolveError::NoSolution)
     }
 }
 
@@ -141,18 +151,18 @@ mod test {
 
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_22::from(include_str!("example.txt"));
-        assert_eq!(solver.solve_first_part(), 37327623);
+        let solver = Solver2024_22::try_from(include_str!("example.txt")).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 37327623);
     }
 
     #[test]
     fn test_solve_second_part() {
-        let solver = Solver2024_22::from(include_str!("example2.txt"));
-        assert_eq!(solver.solve_second_part(), 23);
+        let solver = Solver2024_22::try_from(include_str!("example2.txt")).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 23);
     }
     #[test]
     fn test_solve_second_part_example3() {
-        let solver = Solver2024_22::from(include_str!("example3.txt"));
-        assert_eq!(solver.solve_second_part(), 27);
+        let solver = Solver2024_22::try_from(include_str!("example3.txt")).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 27);
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{ParseError, SolveError, Solver};
 use std::collections::{HashMap, HashSet};
 #[derive(Clone)]
 pub struct Solver2024_23<'a> {
@@ -8,22 +8,24 @@ pub struct Solver2024_23<'a> {
 
 impl Default for Solver2024_23<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2024_23<'a> {
-    fn from(input: &'a str) -> Self {
-        Self {
+impl<'a> TryFrom<&'a str> for Solver2024_23<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        Ok(Self {
             computer_pairs: input
                 .lines()
                 .map(|line| {
-                    let mut computers = line.split("-");
-                    (computers.next().unwrap(), computers.next().unwrap())
+                    line.split_once("-")
+                        .ok_or_else(|| ParseError::at(input, line, "expected `computer-computer`"))
                 })
-                .collect(),
+                .collect::<Result<_, _>>()?,
             computer_map: HashMap::new(),
-        }
+        })
     }
 }
 
@@ -69,7 +71,7 @@ impl<'a> Solver2024_23<'a> {
 }
 
 impl Solver<usize, String> for Solver2024_23<'_> {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut mutated = self.clone();
         mutated.calculate_computer_map();
         let current_parties = mutated
@@ -83,13 +85,13 @@ impl Solver<usize, String> for Solver2024_23<'_> {
             .collect();
         let triplets = mutated.get_one_more_bigger_parties(&current_parties);
 
-        triplets
+        Ok(triplets
             .iter()
             .filter(|triplet| triplet.iter().any(|computer| computer.starts_with("t")))
-            .count()
+            .count())
     }
 
-    fn solve_second_part(&self) -> String {
+    fn solve_second_part(&self) -> Result<String, SolveError> {
         let mut mutated = self.clone();
         mutated.calculate_computer_map();
         let mut current_parties = mutated
@@ -109,7 +111,11 @@ impl Solver<usize, String> for Solver2024_23<'_> {
             }
             current_parties = new_parties;
         }
-        current_parties.iter().next().unwrap().join(",")
+        current_parties
+            .iter()
+            .next()
+            .map(|party| party.join(","))
+            .ok_or(SolveError::NoSolution)
     }
 }
 
@@ -120,13 +126,13 @@ mod test {
 
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_23::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 7);
+        let solver = Solver2024_23::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 7);
     }
 
     #[test]
     fn test_solve_second_part() {
-        let solver = Solver2024_23::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), "co,de,ka,ta");
+        let solver = Solver2024_23::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), "co,de,ka,ta");
     }
 }
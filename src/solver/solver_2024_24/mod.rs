@@ -1,6 +1,6 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 #[derive(PartialEq)]
 enum GateType {
@@ -15,34 +15,34 @@ pub struct Solver2024_24<'a> {
 
 impl Default for Solver2024_24<'_> {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl<'a> From<&'a str> for Solver2024_24<'a> {
-    fn from(input: &'a str) -> Self {
-        let re_initial_values = Regex::new("(.*): (0|1)").unwrap();
-        let re_gates = Regex::new("(.*) (AND|OR|XOR) (.*) -> (.*)").unwrap();
-        let mut parts = input.split("\n\n");
-        let initial_values = parts
-            .next()
-            .unwrap()
+impl<'a> TryFrom<&'a str> for Solver2024_24<'a> {
+    type Error = ParseError;
+
+    fn try_from(input: &'a str) -> Result<Self, Self::Error> {
+        let re_initial_values = Regex::new("^(.*): (0|1)$").unwrap();
+        let re_gates = Regex::new("^(.*) (AND|OR|XOR) (.*) -> (.*)$").unwrap();
+        let (initial_values, gates) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end_of_input(input, "a list of gates"))?;
+        let initial_values = initial_values
             .lines()
             .map(|line| {
-                let captures = re_initial_values.captures(line).unwrap();
-                (
+                let captures = parse::captures(input, &re_initial_values, line)?;
+                Ok((
                     captures.get(1).unwrap().as_str(),
                     captures.get(2).unwrap().as_str() == "1",
-                )
+                ))
             })
-            .collect();
-        let gates = parts
-            .next()
-            .unwrap()
+            .collect::<Result<HashMap<_, _>, ParseError>>()?;
+        let gates: Vec<(&str, (&str, GateType, &str))> = gates
             .lines()
             .map(|line| {
-                let captures = re_gates.captures(line).unwrap();
-                (
+                let captures = parse::captures(input, &re_gates, line)?;
+                Ok((
                     captures.get(4).unwrap().as_str(),
                     (
                         captures.get(1).unwrap().as_str(),
@@ -54,13 +54,48 @@ impl<'a> From<&'a str> for Solver2024_24<'a> {
                         },
                         captures.get(3).unwrap().as_str(),
                     ),
-                )
+                ))
             })
-            .collect();
-        Self {
+            .collect::<Result<_, ParseError>>()?;
+        let outputs: HashSet<&str> = gates.iter().map(|(output, _)| *output).collect();
+        for (_, (lhs, _, rhs)) in gates.iter() {
+            for wire in [lhs, rhs] {
+                if !initial_values.contains_key(wire) && !outputs.contains(wire) {
+                    return Err(ParseError::at(
+                        input,
+                        wire,
+                        format!("there is no wire `{wire}`"),
+                    ));
+                }
+            }
+        }
+        let gates: HashMap<&str, (&str, GateType, &str)> = gates.into_iter().collect();
+        // Wires are evaluated recursively, so a loop among them would never end.
+        let mut resolved: HashSet<&str> = initial_values.keys().copied().collect();
+        let mut pending: Vec<&str> = gates.keys().copied().collect();
+        while !pending.is_empty() {
+            let count = pending.len();
+            pending.retain(|wire| {
+                let (lhs, _, rhs) = &gates[wire];
+                if resolved.contains(lhs) && resolved.contains(rhs) {
+                    resolved.insert(*wire);
+                    return false;
+                }
+                true
+            });
+            if pending.len() == count {
+                let wire = pending.iter().min_by_key(|wire| wire.as_ptr()).unwrap();
+                return Err(ParseError::at(
+                    input,
+                    wire,
+                    format!("the wire `{wire}` depends on itself"),
+                ));
+            }
+        }
+        Ok(Self {
             initial_values,
             gates,
-        }
+        })
     }
 }
 
@@ -108,7 +143,7 @@ impl<'a> Solver2024_24<'a> {
         result
     }
 
-    fn get_xor_input_variables(&self) -> HashMap<usize, &'a str> {
+    fn get_xor_input_variables(&self) -> Result<HashMap<usize, &'a str>, SolveError> {
         let mut result: HashMap<usize, &'a str> = HashMap::new();
         for (variable, (rhs, gate_type, lhs)) in self.gates.iter() {
             if *gate_type != GateType::Xor {
@@ -117,12 +152,14 @@ impl<'a> Solver2024_24<'a> {
             let rhs_index = get_bit_index(rhs);
             let lhs_index = get_bit_index(lhs);
             if rhs_index != lhs_index {
-                panic!("variables {} and {} are not the same", rhs, lhs);
+                return Err(SolveError::Unsupported(format!(
+                    "the wires `{rhs}` and `{lhs}` don't hold the same bit"
+                )));
             } else if let Some(index) = rhs_index {
                 result.insert(index, variable);
             }
         }
-        result
+        Ok(result)
     }
 
     fn is_nth_output_correct(
@@ -130,22 +167,27 @@ impl<'a> Solver2024_24<'a> {
         n: usize,
         xor_input_variables: &HashMap<usize, &'a str>,
     ) -> bool {
-        let &xor_input_variable = xor_input_variables.get(&n).unwrap();
+        let xor_input_variable = xor_input_variables[&n];
         if n == 0 {
             return xor_input_variable == "z00";
         }
 
-        let (rhs, gate_type, lhs) = self.gates.get(format!("z{:0>2}", n).as_str()).unwrap();
-        *gate_type == GateType::Xor && vec![*rhs, *lhs].contains(&xor_input_variable)
+        self.gates
+            .get(format!("z{:0>2}", n).as_str())
+            .is_some_and(|(rhs, gate_type, lhs)| {
+                *gate_type == GateType::Xor && [*rhs, *lhs].contains(&xor_input_variable)
+            })
     }
 
-    fn detect_tangled_variables(&self) -> Vec<String> {
+    fn detect_tangled_variables(&self) -> Result<Vec<String>, SolveError> {
         let mut result = vec![];
-        let xor_input_variables = self.get_xor_input_variables();
+        let xor_input_variables = self.get_xor_input_variables()?;
         for (&n, &variable) in xor_input_variables.iter() {
             let output = format!("z{:0>2}", n);
             if !self.is_nth_output_correct(n, &xor_input_variables) {
-                let (rhs, gate_type, lhs) = self.gates.get(output.as_str()).unwrap();
+                let (rhs, gate_type, lhs) = self.gates.get(output.as_str()).ok_or_else(|| {
+                    SolveError::Unsupported(format!("there is no wire `{output}`"))
+                })?;
                 if *gate_type != GateType::Xor {
                     // if the gate is not an xor gate, then the output is incorrect
                     // We need to swap with a wire
@@ -157,8 +199,11 @@ impl<'a> Solver2024_24<'a> {
                     // the wire with the or gate is the correct wire
                     // Swap the other wire with xor_input_variable
                     result.push(variable.to_string());
-                    let (_, gate_type, _) = self.gates.get(lhs).unwrap();
-                    if *gate_type == GateType::Or {
+                    let is_or_gate = self
+                        .gates
+                        .get(lhs)
+                        .is_some_and(|(_, gate_type, _)| *gate_type == GateType::Or);
+                    if is_or_gate {
                         result.push(rhs.to_string());
                     } else {
                         result.push(lhs.to_string());
@@ -178,19 +223,19 @@ impl<'a> Solver2024_24<'a> {
             }
         }
 
-        result
+        Ok(result)
     }
 }
 
 impl Solver<usize, String> for Solver2024_24<'_> {
-    fn solve_first_part(&self) -> usize {
-        self.get_z_value()
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
+        Ok(self.get_z_value())
     }
 
-    fn solve_second_part(&self) -> String {
-        let mut result = self.detect_tangled_variables();
+    fn solve_second_part(&self) -> Result<String, SolveError> {
+        let mut result = self.detect_tangled_variables()?;
         result.sort();
-        result.join(",")
+        Ok(result.join(","))
     }
 }
 
@@ -202,13 +247,25 @@ mod test {
 
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_24::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 4);
+        let solver = Solver2024_24::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 4);
     }
 
     #[test]
     fn test_solve_first_part_with_example2() {
-        let solver = Solver2024_24::from(EXAMPLE2);
-        assert_eq!(solver.solve_first_part(), 2024);
+        let solver = Solver2024_24::try_from(EXAMPLE2).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 2024);
+    }
+
+    #[test]
+    fn test_reject_unknown_wires() {
+        assert_eq!(
+            Solver2024_24::try_from("x00: 1\n\nx00 AND y00 -> z00").err(),
+            Some(ParseError::new(3, 9, "there is no wire `y00`"))
+        );
+        assert_eq!(
+            Solver2024_24::try_from("x00: 1\n\nx00 AND z01 -> z00\nz00 OR x00 -> z01").err(),
+            Some(ParseError::new(3, 16, "the wire `z00` depends on itself"))
+        );
     }
 }
@@ -1,4 +1,4 @@
-use super::Solver;
+use super::{parse, ParseError, SolveError, Solver};
 use regex::Regex;
 use std::collections::HashMap;
 
@@ -9,15 +9,21 @@ pub struct Solver2024_25 {
 
 impl Default for Solver2024_25 {
     fn default() -> Self {
-        Self::from(include_str!("input.txt"))
+        Self::try_from(include_str!("input.txt")).unwrap()
     }
 }
 
-impl From<&str> for Solver2024_25 {
-    fn from(input: &str) -> Self {
+impl TryFrom<&str> for Solver2024_25 {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        parse::allowed_chars(input, input, ".#\n")?;
         let mut keys = HashMap::new();
         let mut locks = HashMap::new();
         for key_or_lock in input.split("\n\n") {
+            if key_or_lock.lines().any(|line| line.len() != 5) {
+                return Err(ParseError::at(input, key_or_lock, "expected 5 columns"));
+            }
             let is_lock = key_or_lock.starts_with("#");
             let mut shape = [0; 5];
             let re_first_and_last_line = Regex::new(r"(\n.*$|^.*\n)").unwrap();
@@ -33,12 +39,12 @@ impl From<&str> for Solver2024_25 {
                 *keys.entry(shape).or_default() += 1;
             }
         }
-        Self { keys, locks }
+        Ok(Self { keys, locks })
     }
 }
 
 impl Solver<usize, usize> for Solver2024_25 {
-    fn solve_first_part(&self) -> usize {
+    fn solve_first_part(&self) -> Result<usize, SolveError> {
         let mut result = 0;
         for (key, key_count) in &self.keys {
             for (lock, lock_count) in &self.locks {
@@ -47,11 +53,11 @@ impl Solver<usize, usize> for Solver2024_25 {
                 }
             }
         }
-        result
+        Ok(result)
     }
 
-    fn solve_second_part(&self) -> usize {
-        0
+    fn solve_second_part(&self) -> Result<usize, SolveError> {
+        Ok(0)
     }
 }
 
@@ -62,13 +68,13 @@ mod test {
 
     #[test]
     fn test_solve_first_part() {
-        let solver = Solver2024_25::from(EXAMPLE);
-        assert_eq!(solver.solve_first_part(), 3);
+        let solver = Solver2024_25::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_first_part().unwrap(), 3);
     }
 
     #[test]
     fn test_solve_second_part() {
-        let solver = Solver2024_25::from(EXAMPLE);
-        assert_eq!(solver.solve_second_part(), 0);
+        let solver = Solver2024_25::try_from(EXAMPLE).unwrap();
+        assert_eq!(solver.solve_second_part().unwrap(), 0);
     }
 }